@@ -0,0 +1,554 @@
+use std::fs;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use advent_of_code_2020::day_01::{product_of_2020_sum_pair, product_of_2020_sum_triplet};
+use advent_of_code_2020::day_02::{
+    count_policies_satisfied_by_passwords, to_policy_and_password,
+    OccurrenceRestrictedPasswordPolicy, Password, PositionallyRestrictedPasswordPolicy,
+};
+use advent_of_code_2020::day_03::MovementDirection::{Down, Right};
+use advent_of_code_2020::day_03::{
+    count_encountered_trees_for_movement_sequence,
+    product_of_tree_encounters_for_movement_sequences,
+};
+use advent_of_code_2020::day_04::{
+    count_valid_relaxed_validation_passports_in_text,
+    count_valid_strict_validation_passports_in_text,
+};
+use advent_of_code_2020::day_05::{find_highest_seat_id_on_plane, find_my_empty_seat_id};
+use advent_of_code_2020::day_06::{
+    count_total_group_intersecting_positive_answers, count_total_group_unified_positive_answers,
+};
+use advent_of_code_2020::day_07::{
+    count_bags_contained_inside, count_bags_that_eventually_contain,
+};
+use advent_of_code_2020::day_08::{
+    get_accumulator_value_after_termination_of_fixed_instructions,
+    get_accumulator_value_before_repeated_instruction,
+};
+use advent_of_code_2020::day_09::{find_first_xmas_encoding_error, get_encryption_weakness};
+use advent_of_code_2020::day_10::{
+    count_distinct_joltage_adapter_arrangements,
+    product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in,
+};
+use advent_of_code_2020::day_11::{
+    count_occupied_seats_after_line_of_sight_occupancy_stabilisation,
+    count_occupied_seats_after_occupancy_stabilisation,
+};
+use advent_of_code_2020::day_12::{
+    get_manhattan_distance_to_directed_location,
+    get_manhattan_distance_to_directed_location_with_waypoint_navigation,
+};
+use advent_of_code_2020::day_13::{
+    get_earliest_timestamp_matching_bus_offsets, get_product_of_id_of_earliest_bus_and_wait_time,
+};
+use advent_of_code_2020::day_14::{
+    sum_of_memory_values_after_docking_program,
+    sum_of_memory_values_after_floating_address_docking_program,
+};
+use advent_of_code_2020::day_15::nth_spoken_number_in_recitation_game;
+use advent_of_code_2020::day_16::{
+    product_of_my_departure_field_values, ticket_scanning_error_rate_for_input_nearby_tickets,
+};
+use advent_of_code_2020::day_17::{
+    count_active_cubes_after_3d_boot_cycles, count_active_cubes_after_4d_boot_cycles,
+};
+use advent_of_code_2020::day_18::{
+    sum_of_expression_values_with_addition_precedence,
+    sum_of_expression_values_with_equal_precedence,
+};
+use advent_of_code_2020::day_19::{
+    count_messages_matching_rule_0_of_input,
+    count_messages_matching_rule_0_of_input_with_looping_rules,
+};
+use advent_of_code_2020::day_20::{
+    product_of_corner_tile_ids, render_assembled_image, water_roughness_of_assembled_image,
+};
+use advent_of_code_2020::day_21::{
+    canonical_dangerous_ingredient_list, count_appearances_of_allergen_free_ingredients,
+};
+use advent_of_code_2020::day_22::{
+    play_crab_combat_with_log, winning_player_score_of_combat,
+    winning_player_score_of_recursive_combat, CombatVariant,
+};
+use advent_of_code_2020::day_23::{
+    cup_labels_after_crab_moves, product_of_cups_after_cup_1_in_million_cup_game,
+};
+use advent_of_code_2020::day_24::{count_black_tiles_after_days, count_black_tiles_after_flipping};
+use advent_of_code_2020::day_25::encryption_key_from_public_keys;
+use advent_of_code_2020::input::ChallengeInput;
+
+fn sample_input(day: u8) -> ChallengeInput {
+    ChallengeInput::new(&fs::read_to_string(format!("sample_data/day_{:02}.txt", day)).unwrap())
+}
+
+fn sample_input_lines(day: u8) -> Vec<String> {
+    sample_input(day).lines()
+}
+
+fn sample_input_records(day: u8) -> Vec<String> {
+    sample_input(day).records()
+}
+
+fn bench_with_input<T, R>(c: &mut Criterion, name: &str, input: &T, solve: impl Fn(T) -> R)
+where
+    T: Clone,
+{
+    c.bench_function(name, |b| {
+        b.iter_batched(|| input.clone(), &solve, BatchSize::SmallInput)
+    });
+}
+
+fn day_01(c: &mut Criterion) {
+    let numbers: Vec<u64> = sample_input_lines(1)
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+    c.bench_function("day_01/product_of_2020_sum_pair", |b| {
+        b.iter(|| product_of_2020_sum_pair(&numbers))
+    });
+    c.bench_function("day_01/product_of_2020_sum_triplet", |b| {
+        b.iter(|| product_of_2020_sum_triplet(&numbers))
+    });
+}
+
+fn day_02(c: &mut Criterion) {
+    let lines = sample_input_lines(2);
+
+    bench_with_input(
+        c,
+        "day_02/count_policies_satisfied_by_passwords/occurrence",
+        &lines,
+        |lines| {
+            count_policies_satisfied_by_passwords(
+                lines
+                    .iter()
+                    .map(to_policy_and_password)
+                    .collect::<anyhow::Result<Vec<(OccurrenceRestrictedPasswordPolicy, Password)>>>(
+                    )
+                    .unwrap(),
+            )
+        },
+    );
+    bench_with_input(
+        c,
+        "day_02/count_policies_satisfied_by_passwords/positional",
+        &lines,
+        |lines| {
+            count_policies_satisfied_by_passwords(
+                lines
+                    .iter()
+                    .map(to_policy_and_password)
+                    .collect::<anyhow::Result<Vec<(PositionallyRestrictedPasswordPolicy, Password)>>>()
+                    .unwrap(),
+            )
+        },
+    );
+}
+
+fn day_03(c: &mut Criterion) {
+    let lines = sample_input_lines(3);
+
+    bench_with_input(
+        c,
+        "day_03/count_encountered_trees_for_movement_sequence",
+        &lines,
+        |lines| {
+            count_encountered_trees_for_movement_sequence(lines, vec![Right, Right, Right, Down])
+        },
+    );
+    bench_with_input(
+        c,
+        "day_03/product_of_tree_encounters_for_movement_sequences",
+        &lines,
+        |lines| {
+            product_of_tree_encounters_for_movement_sequences(
+                lines,
+                vec![
+                    vec![Right, Down],
+                    vec![Right, Right, Right, Down],
+                    vec![Right, Right, Right, Right, Right, Down],
+                    vec![Right, Right, Right, Right, Right, Right, Right, Down],
+                    vec![Right, Down, Down],
+                ],
+            )
+        },
+    );
+}
+
+fn day_04(c: &mut Criterion) {
+    let records = sample_input_records(4);
+
+    bench_with_input(
+        c,
+        "day_04/count_valid_relaxed_validation_passports_in_text",
+        &records,
+        count_valid_relaxed_validation_passports_in_text,
+    );
+    bench_with_input(
+        c,
+        "day_04/count_valid_strict_validation_passports_in_text",
+        &records,
+        count_valid_strict_validation_passports_in_text,
+    );
+}
+
+fn day_05(c: &mut Criterion) {
+    let lines = sample_input_lines(5);
+
+    bench_with_input(
+        c,
+        "day_05/find_highest_seat_id_on_plane",
+        &lines,
+        find_highest_seat_id_on_plane,
+    );
+    bench_with_input(
+        c,
+        "day_05/find_my_empty_seat_id",
+        &lines,
+        find_my_empty_seat_id,
+    );
+}
+
+fn day_06(c: &mut Criterion) {
+    let records = sample_input_records(6);
+
+    bench_with_input(
+        c,
+        "day_06/count_total_group_unified_positive_answers",
+        &records,
+        count_total_group_unified_positive_answers,
+    );
+    bench_with_input(
+        c,
+        "day_06/count_total_group_intersecting_positive_answers",
+        &records,
+        count_total_group_intersecting_positive_answers,
+    );
+}
+
+fn day_07(c: &mut Criterion) {
+    let lines = sample_input_lines(7);
+
+    bench_with_input(
+        c,
+        "day_07/count_bags_that_eventually_contain",
+        &lines,
+        |lines| count_bags_that_eventually_contain(lines, "shiny gold"),
+    );
+    bench_with_input(c, "day_07/count_bags_contained_inside", &lines, |lines| {
+        count_bags_contained_inside(lines, "shiny gold")
+    });
+}
+
+fn day_08(c: &mut Criterion) {
+    let lines = sample_input_lines(8);
+
+    bench_with_input(
+        c,
+        "day_08/get_accumulator_value_before_repeated_instruction",
+        &lines,
+        get_accumulator_value_before_repeated_instruction,
+    );
+    bench_with_input(
+        c,
+        "day_08/get_accumulator_value_after_termination_of_fixed_instructions",
+        &lines,
+        get_accumulator_value_after_termination_of_fixed_instructions,
+    );
+}
+
+fn day_09(c: &mut Criterion) {
+    let lines = sample_input_lines(9);
+
+    bench_with_input(
+        c,
+        "day_09/find_first_xmas_encoding_error",
+        &lines,
+        |lines| find_first_xmas_encoding_error(lines, 25),
+    );
+    bench_with_input(c, "day_09/get_encryption_weakness", &lines, |lines| {
+        get_encryption_weakness(lines, 25)
+    });
+}
+
+fn day_10(c: &mut Criterion) {
+    let lines = sample_input_lines(10);
+
+    bench_with_input(
+        c,
+        "day_10/product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in",
+        &lines,
+        product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in,
+    );
+    bench_with_input(
+        c,
+        "day_10/count_distinct_joltage_adapter_arrangements",
+        &lines,
+        count_distinct_joltage_adapter_arrangements,
+    );
+}
+
+fn day_11(c: &mut Criterion) {
+    let lines = sample_input_lines(11);
+
+    bench_with_input(
+        c,
+        "day_11/count_occupied_seats_after_occupancy_stabilisation",
+        &lines,
+        count_occupied_seats_after_occupancy_stabilisation,
+    );
+    bench_with_input(
+        c,
+        "day_11/count_occupied_seats_after_line_of_sight_occupancy_stabilisation",
+        &lines,
+        count_occupied_seats_after_line_of_sight_occupancy_stabilisation,
+    );
+}
+
+fn day_12(c: &mut Criterion) {
+    let lines = sample_input_lines(12);
+
+    bench_with_input(
+        c,
+        "day_12/get_manhattan_distance_to_directed_location",
+        &lines,
+        get_manhattan_distance_to_directed_location,
+    );
+    bench_with_input(
+        c,
+        "day_12/get_manhattan_distance_to_directed_location_with_waypoint_navigation",
+        &lines,
+        get_manhattan_distance_to_directed_location_with_waypoint_navigation,
+    );
+}
+
+fn day_13(c: &mut Criterion) {
+    let lines = sample_input_lines(13);
+
+    bench_with_input(
+        c,
+        "day_13/get_product_of_id_of_earliest_bus_and_wait_time",
+        &lines,
+        get_product_of_id_of_earliest_bus_and_wait_time,
+    );
+    bench_with_input(
+        c,
+        "day_13/get_earliest_timestamp_matching_bus_offsets",
+        &lines,
+        get_earliest_timestamp_matching_bus_offsets,
+    );
+}
+
+fn day_14(c: &mut Criterion) {
+    let lines = sample_input_lines(14);
+
+    bench_with_input(
+        c,
+        "day_14/sum_of_memory_values_after_docking_program",
+        &lines,
+        sum_of_memory_values_after_docking_program,
+    );
+    bench_with_input(
+        c,
+        "day_14/sum_of_memory_values_after_floating_address_docking_program",
+        &lines,
+        sum_of_memory_values_after_floating_address_docking_program,
+    );
+}
+
+fn day_15(c: &mut Criterion) {
+    let starting_numbers = sample_input_lines(15).first().unwrap().clone();
+
+    bench_with_input(
+        c,
+        "day_15/nth_spoken_number_in_recitation_game/2020",
+        &starting_numbers,
+        |starting_numbers| nth_spoken_number_in_recitation_game(2020, starting_numbers),
+    );
+    bench_with_input(
+        c,
+        "day_15/nth_spoken_number_in_recitation_game/30000000",
+        &starting_numbers,
+        |starting_numbers| nth_spoken_number_in_recitation_game(30000000, starting_numbers),
+    );
+}
+
+fn day_16(c: &mut Criterion) {
+    let records = sample_input_records(16);
+
+    bench_with_input(
+        c,
+        "day_16/ticket_scanning_error_rate_for_input_nearby_tickets",
+        &records,
+        ticket_scanning_error_rate_for_input_nearby_tickets,
+    );
+    bench_with_input(
+        c,
+        "day_16/product_of_my_departure_field_values",
+        &records,
+        product_of_my_departure_field_values,
+    );
+}
+
+fn day_17(c: &mut Criterion) {
+    let lines = sample_input_lines(17);
+
+    bench_with_input(
+        c,
+        "day_17/count_active_cubes_after_3d_boot_cycles",
+        &lines,
+        count_active_cubes_after_3d_boot_cycles,
+    );
+    bench_with_input(
+        c,
+        "day_17/count_active_cubes_after_4d_boot_cycles",
+        &lines,
+        count_active_cubes_after_4d_boot_cycles,
+    );
+}
+
+fn day_18(c: &mut Criterion) {
+    let lines = sample_input_lines(18);
+
+    bench_with_input(
+        c,
+        "day_18/sum_of_expression_values_with_equal_precedence",
+        &lines,
+        sum_of_expression_values_with_equal_precedence,
+    );
+    bench_with_input(
+        c,
+        "day_18/sum_of_expression_values_with_addition_precedence",
+        &lines,
+        sum_of_expression_values_with_addition_precedence,
+    );
+}
+
+fn day_19(c: &mut Criterion) {
+    let records = sample_input_records(19);
+
+    bench_with_input(
+        c,
+        "day_19/count_messages_matching_rule_0_of_input",
+        &records,
+        count_messages_matching_rule_0_of_input,
+    );
+    bench_with_input(
+        c,
+        "day_19/count_messages_matching_rule_0_of_input_with_looping_rules",
+        &records,
+        count_messages_matching_rule_0_of_input_with_looping_rules,
+    );
+}
+
+fn day_20(c: &mut Criterion) {
+    let records = sample_input_records(20);
+
+    bench_with_input(
+        c,
+        "day_20/product_of_corner_tile_ids",
+        &records,
+        product_of_corner_tile_ids,
+    );
+    bench_with_input(
+        c,
+        "day_20/water_roughness_of_assembled_image",
+        &records,
+        water_roughness_of_assembled_image,
+    );
+    bench_with_input(
+        c,
+        "day_20/render_assembled_image",
+        &records,
+        render_assembled_image,
+    );
+}
+
+fn day_21(c: &mut Criterion) {
+    let lines = sample_input_lines(21);
+
+    bench_with_input(
+        c,
+        "day_21/count_appearances_of_allergen_free_ingredients",
+        &lines,
+        count_appearances_of_allergen_free_ingredients,
+    );
+    bench_with_input(
+        c,
+        "day_21/canonical_dangerous_ingredient_list",
+        &lines,
+        canonical_dangerous_ingredient_list,
+    );
+}
+
+fn day_22(c: &mut Criterion) {
+    let records = sample_input_records(22);
+
+    bench_with_input(
+        c,
+        "day_22/winning_player_score_of_combat",
+        &records,
+        winning_player_score_of_combat,
+    );
+    bench_with_input(
+        c,
+        "day_22/winning_player_score_of_recursive_combat",
+        &records,
+        winning_player_score_of_recursive_combat,
+    );
+    bench_with_input(c, "day_22/play_crab_combat_with_log", &records, |records| {
+        play_crab_combat_with_log(records, CombatVariant::Recursive)
+    });
+}
+
+fn day_23(c: &mut Criterion) {
+    let cup_labels = sample_input_lines(23).first().unwrap().clone();
+
+    c.bench_function("day_23/cup_labels_after_crab_moves", |b| {
+        b.iter(|| cup_labels_after_crab_moves(&cup_labels, 100))
+    });
+    c.bench_function(
+        "day_23/product_of_cups_after_cup_1_in_million_cup_game",
+        |b| b.iter(|| product_of_cups_after_cup_1_in_million_cup_game(&cup_labels)),
+    );
+}
+
+fn day_24(c: &mut Criterion) {
+    let lines = sample_input_lines(24);
+
+    bench_with_input(
+        c,
+        "day_24/count_black_tiles_after_flipping",
+        &lines,
+        count_black_tiles_after_flipping,
+    );
+    bench_with_input(c, "day_24/count_black_tiles_after_days", &lines, |lines| {
+        count_black_tiles_after_days(lines, 100)
+    });
+}
+
+fn day_25(c: &mut Criterion) {
+    let lines = sample_input_lines(25);
+
+    bench_with_input(
+        c,
+        "day_25/encryption_key_from_public_keys",
+        &lines,
+        encryption_key_from_public_keys,
+    );
+}
+
+criterion_group!(
+    benches, day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10,
+    day_11, day_12, day_13, day_14, day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_24,
+    day_25
+);
+criterion_group! {
+    name = slow_benches;
+    config = Criterion::default().sample_size(10);
+    targets = day_15, day_23
+}
+criterion_main!(benches, slow_benches);
@@ -1,17 +1,112 @@
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 
-pub struct Answer<T: Display> {
-    value: T,
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(u64),
+    SignedInteger(i64),
+    String(String),
+}
+
+impl Answer {
+    pub fn answer_type(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::SignedInteger(_) => "signed_integer",
+            Answer::String(_) => "string",
+        }
+    }
 }
 
-impl<T: Display> Answer<T> {
-    pub fn new(value: T) -> Self {
-        Answer { value }
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Integer(a), Answer::Integer(b)) => a == b,
+            (Answer::SignedInteger(a), Answer::SignedInteger(b)) => a == b,
+            (Answer::Integer(a), Answer::SignedInteger(b))
+            | (Answer::SignedInteger(b), Answer::Integer(a)) => i64::try_from(*a) == Ok(*b),
+            (Answer::String(a), Answer::String(b)) => a == b,
+            _ => false,
+        }
     }
 }
 
-impl<T: Display> Display for Answer<T> {
+impl Eq for Answer {}
+
+impl Display for Answer {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Answer: {}", self.value)
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::SignedInteger(value) => write!(f, "{}", value),
+            Answer::String(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::SignedInteger(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn compares_integer_answers_by_value() {
+        assert_that(&Answer::from(5_usize)).is_equal_to(Answer::from(5_u64));
+        assert_that(&Answer::from(5_u32)).is_equal_to(Answer::from(5_i64));
+        assert_that(&Answer::from(-5_i64)).is_not_equal_to(Answer::Integer(u64::MAX));
+        assert_that(&Answer::from(5_u64)).is_not_equal_to(Answer::from("5".to_string()));
+    }
+
+    #[test]
+    fn serialises_answers_as_json_values() {
+        let answers = vec![
+            Answer::from(514579_u64),
+            Answer::from(-3_i64),
+            Answer::from("67384529".to_string()),
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+
+        assert_that(&json).is_equal_to(r#"[514579,-3,"67384529"]"#.to_string());
+        assert_that(&serde_json::from_str::<Vec<Answer>>(&json).unwrap()).is_equal_to(answers);
+    }
+
+    #[test]
+    fn displays_answer_value() {
+        assert_that(&Answer::from(-3_i64).to_string()).is_equal_to("-3".to_string());
+        assert_that(&Answer::from("abc".to_string()).to_string()).is_equal_to("abc".to_string());
     }
 }
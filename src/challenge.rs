@@ -1,16 +1,25 @@
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+use lazy_static::lazy_static;
 use regex::Regex;
 
 use crate::challenge::ChallengePart::{One, Two};
+use crate::solver::SolverRegistry;
 
-#[derive(Debug, Copy, Clone)]
+const LAST_DAY: u8 = 25;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Challenge {
     day: u8,
     part: ChallengePart,
 }
 
 impl Challenge {
+    pub fn new(day: u8, part: ChallengePart) -> Self {
+        Challenge { day, part }
+    }
+
     pub fn day(&self) -> u8 {
         self.day
     }
@@ -27,15 +36,34 @@ impl FromStr for Challenge {
         let re = Regex::new(r"^(\d{1,2})\.(\d)$").unwrap();
         match re.captures(s) {
             Some(captures) => Ok(Challenge {
-                day: captures.get(1).unwrap().as_str().parse().unwrap(),
-                part: captures.get(2).unwrap().as_str().parse().unwrap(),
+                day: day_from_string(captures.get(1).unwrap().as_str())?,
+                part: captures.get(2).unwrap().as_str().parse()?,
             }),
             None => Err(anyhow::Error::msg("Could not parse challenge")),
         }
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+fn day_from_string(s: &str) -> anyhow::Result<u8> {
+    let day = s.parse()?;
+
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(anyhow::Error::msg(format!(
+            "Day {} is not between 1 and {}",
+            day, LAST_DAY
+        )));
+    }
+
+    Ok(day)
+}
+
+impl Display for Challenge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.day, self.part)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ChallengePart {
     One,
     Two,
@@ -59,3 +87,146 @@ impl FromStr for ChallengePart {
         }
     }
 }
+
+impl ChallengePart {
+    pub fn number(&self) -> u8 {
+        match self {
+            One => 1,
+            Two => 2,
+        }
+    }
+}
+
+impl Display for ChallengePart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChallengeSelection {
+    first_day: u8,
+    last_day: u8,
+    part: Option<ChallengePart>,
+}
+
+impl ChallengeSelection {
+    pub fn all() -> Self {
+        Self::from_days(1, LAST_DAY)
+    }
+
+    pub fn challenges(&self, registry: &SolverRegistry) -> Vec<Challenge> {
+        (self.first_day..=self.last_day)
+            .flat_map(|day| match self.part {
+                Some(part) => vec![Challenge::new(day, part)],
+                None => registry.challenges_of_day(day),
+            })
+            .collect()
+    }
+
+    fn from_days(first_day: u8, last_day: u8) -> Self {
+        ChallengeSelection {
+            first_day,
+            last_day,
+            part: None,
+        }
+    }
+}
+
+impl FromStr for ChallengeSelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref DAY_RE: Regex = Regex::new(r"^(\d{1,2})\.\*$").unwrap();
+            static ref RANGE_RE: Regex = Regex::new(r"^(\d{1,2})-(\d{1,2})$").unwrap();
+        }
+
+        if let Some(captures) = DAY_RE.captures(s) {
+            let day = day_from_string(captures.get(1).unwrap().as_str())?;
+            Ok(Self::from_days(day, day))
+        } else if let Some(captures) = RANGE_RE.captures(s) {
+            let first_day = day_from_string(captures.get(1).unwrap().as_str())?;
+            let last_day = day_from_string(captures.get(2).unwrap().as_str())?;
+            if first_day > last_day {
+                return Err(anyhow::Error::msg(format!(
+                    "Challenge range {} is empty",
+                    s
+                )));
+            }
+            Ok(Self::from_days(first_day, last_day))
+        } else {
+            let challenge: Challenge = s.parse()?;
+            Ok(ChallengeSelection {
+                first_day: challenge.day(),
+                last_day: challenge.day(),
+                part: Some(challenge.part()),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn challenge_names(selection: &str) -> Vec<String> {
+        selection
+            .parse::<ChallengeSelection>()
+            .unwrap()
+            .challenges(&SolverRegistry::default())
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn selects_single_challenge() {
+        assert_that(&challenge_names("4.2")).is_equal_to(vec!["4.2".to_string()]);
+    }
+
+    #[test]
+    fn selects_every_part_of_day() {
+        assert_that(&challenge_names("4.*"))
+            .is_equal_to(vec!["4.1".to_string(), "4.2".to_string()]);
+    }
+
+    #[test]
+    fn selects_range_of_days_without_day_25_part_2() {
+        assert_that(&challenge_names("24-25")).is_equal_to(vec![
+            "24.1".to_string(),
+            "24.2".to_string(),
+            "25.1".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn selects_every_challenge() {
+        assert_that(
+            &ChallengeSelection::all()
+                .challenges(&SolverRegistry::default())
+                .len(),
+        )
+        .is_equal_to(49);
+    }
+
+    #[test]
+    fn fails_to_select_empty_range() {
+        assert_that(&"5-3".parse::<ChallengeSelection>().is_err()).is_true();
+    }
+
+    #[test]
+    fn fails_to_select_days_outside_advent() {
+        assert_that(&"24-27".parse::<ChallengeSelection>().is_err()).is_true();
+        assert_that(&"0.1".parse::<ChallengeSelection>().is_err()).is_true();
+        assert_that(&"26.*".parse::<ChallengeSelection>().is_err()).is_true();
+    }
+
+    #[test]
+    fn parses_day_25_part_2_for_the_solver_to_reject() {
+        assert_that(&"25.2".parse::<Challenge>().unwrap().to_string())
+            .is_equal_to("25.2".to_string());
+    }
+}
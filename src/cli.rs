@@ -1,27 +1,130 @@
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use structopt::StructOpt;
 
-use advent_of_code_2020::challenge::Challenge;
+use advent_of_code_2020::challenge::{Challenge, ChallengeSelection};
+use advent_of_code_2020::solver::SolverRegistry;
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Advent of Code 2020")]
 pub struct Opt {
-    /// Choose challenge
+    /// Choose challenge, a range of days or every part of a day (e.g. 1.1, 1-16 or 4.*)
     #[structopt(short, long, default_value = "1.1")]
-    challenge: Challenge,
+    challenge: ChallengeSelection,
 
-    /// Input file
+    /// Run every challenge
+    #[structopt(long, conflicts_with = "challenge")]
+    all: bool,
+
+    /// Input file, or - to read from stdin, only valid when running a single challenge
     #[structopt(short, long, parse(from_os_str))]
-    input: PathBuf,
+    input: Option<PathBuf>,
+
+    /// Input text, only valid when running a single challenge
+    #[structopt(long, conflicts_with = "input")]
+    input_text: Option<String>,
+
+    /// Directory of cached day_NN.txt input files used when no input file is given
+    #[structopt(long, default_value = "inputs", parse(from_os_str))]
+    input_dir: PathBuf,
+
+    /// Session token used to download inputs missing from the input directory
+    #[structopt(long, env = "AOC_SESSION", hide_env_values = true)]
+    session_token: Option<String>,
+
+    /// Report time spent reading input, parsing it and solving
+    #[structopt(long)]
+    time: bool,
+
+    /// Number of times to run each challenge when timing
+    #[structopt(long, requires = "time")]
+    repeat: Option<NonZeroUsize>,
+
+    /// Compare each answer with the expected answers file and report pass, fail or unknown
+    #[structopt(long)]
+    check: bool,
+
+    /// JSON file of expected answers keyed by day and part (e.g. {"1": {"1": 514579}})
+    #[structopt(long, default_value = "answers.json", parse(from_os_str))]
+    answers: PathBuf,
+
+    /// Print the challenge's rendering instead of its answer (the day 20 image or day 22 combat log)
+    #[structopt(long, conflicts_with_all = &["all", "time", "check"])]
+    render: bool,
+
+    /// Output format: text, json (one object per challenge) or plain (answers only)
+    #[structopt(long, default_value = "text", possible_values = &["text", "json", "plain"])]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Plain,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "plain" => Ok(OutputFormat::Plain),
+            _ => Err(anyhow::Error::msg(format!("Unknown output format {}", s))),
+        }
+    }
 }
 
 impl Opt {
-    pub fn challenge(&self) -> Challenge {
-        self.challenge
+    pub fn challenges(&self, registry: &SolverRegistry) -> Vec<Challenge> {
+        if self.all {
+            ChallengeSelection::all().challenges(registry)
+        } else {
+            self.challenge.challenges(registry)
+        }
     }
 
-    pub fn input(&self) -> PathBuf {
+    pub fn input(&self) -> Option<PathBuf> {
         self.input.clone()
     }
+
+    pub fn input_text(&self) -> Option<String> {
+        self.input_text.clone()
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        self.input_dir.clone()
+    }
+
+    pub fn session_token(&self) -> Option<String> {
+        self.session_token.clone()
+    }
+
+    pub fn time(&self) -> bool {
+        self.time
+    }
+
+    pub fn repeat(&self) -> usize {
+        self.repeat.map_or(1, NonZeroUsize::get)
+    }
+
+    pub fn check(&self) -> bool {
+        self.check
+    }
+
+    pub fn answers(&self) -> PathBuf {
+        self.answers.clone()
+    }
+
+    pub fn render(&self) -> bool {
+        self.render
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
 }
@@ -1,3 +1,10 @@
+use std::num::ParseIntError;
+
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 fn find_2020_sum_pair(numbers: &[u64]) -> Option<(u64, u64)> {
     for a in numbers {
         for b in numbers {
@@ -23,16 +30,44 @@ fn find_2020_sum_triplet(numbers: &[u64]) -> Option<(u64, u64, u64)> {
 }
 
 pub fn product_of_2020_sum_pair(numbers: &[u64]) -> Option<u64> {
-    match find_2020_sum_pair(numbers) {
-        Some((a, b)) => Some(a * b),
-        None => None,
-    }
+    find_2020_sum_pair(numbers).map(|(a, b)| a * b)
 }
 
 pub fn product_of_2020_sum_triplet(numbers: &[u64]) -> Option<u64> {
-    match find_2020_sum_triplet(numbers) {
-        Some((a, b, c)) => Some(a * b * c),
-        None => None,
+    find_2020_sum_triplet(numbers).map(|(a, b, c)| a * b * c)
+}
+
+pub struct Day01Solver;
+
+impl Solver for Day01Solver {
+    type Input = Vec<u64>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        Ok(input
+            .lines()
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<Vec<u64>, ParseIntError>>()?)
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => product_of_2020_sum_pair(&input).ok_or_else(|| {
+                anyhow::Error::new(ChallengeError::NoSolutionFound(
+                    "No pair sums to 2020".to_string(),
+                ))
+            }),
+            ChallengePart::Two => product_of_2020_sum_triplet(&input).ok_or_else(|| {
+                anyhow::Error::new(ChallengeError::NoSolutionFound(
+                    "No triplet sums to 2020".to_string(),
+                ))
+            }),
+        }
     }
 }
 
@@ -46,13 +81,13 @@ mod tests {
     fn finds_pair_that_sums_to_2020() {
         let numbers = vec![1721, 979, 366, 299, 675, 1456];
 
-        assert_that(&product_of_2020_sum_pair(&*numbers).unwrap()).is_equal_to(299 * 1721);
+        assert_that(&product_of_2020_sum_pair(&numbers).unwrap()).is_equal_to(299 * 1721);
     }
 
     #[test]
     fn finds_triplet_that_sums_to_2020() {
         let numbers = vec![1721, 979, 366, 299, 675, 1456];
 
-        assert_that(&product_of_2020_sum_triplet(&*numbers).unwrap()).is_equal_to(979 * 366 * 675);
+        assert_that(&product_of_2020_sum_triplet(&numbers).unwrap()).is_equal_to(979 * 366 * 675);
     }
 }
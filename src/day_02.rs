@@ -1,9 +1,13 @@
-use std::convert::Infallible;
+use std::convert::{Infallible, TryFrom};
 use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 lazy_static! {
     static ref POLICY_REGEX: Regex = Regex::new(r"^(\d+)-(\d+) (\w)$").unwrap();
 }
@@ -12,6 +16,28 @@ pub trait PasswordPolicy {
     fn is_satisfied_by(&self, password: &Password) -> bool;
 }
 
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub struct PasswordPolicyDescription {
+    restricted_character: char,
+    first_number: u32,
+    second_number: u32,
+}
+
+impl FromStr for PasswordPolicyDescription {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match POLICY_REGEX.captures(s) {
+            Some(captures) => Ok(PasswordPolicyDescription {
+                restricted_character: captures.get(3).unwrap().as_str().parse()?,
+                first_number: captures.get(1).unwrap().as_str().parse()?,
+                second_number: captures.get(2).unwrap().as_str().parse()?,
+            }),
+            None => Err(anyhow::Error::msg("could not parse Password Policy")),
+        }
+    }
+}
+
 #[cfg_attr(test, derive(Debug, Eq, PartialEq))]
 pub struct OccurrenceRestrictedPasswordPolicy {
     restricted_character: char,
@@ -45,18 +71,23 @@ impl PasswordPolicy for OccurrenceRestrictedPasswordPolicy {
     }
 }
 
+impl From<&PasswordPolicyDescription> for OccurrenceRestrictedPasswordPolicy {
+    fn from(description: &PasswordPolicyDescription) -> Self {
+        OccurrenceRestrictedPasswordPolicy::new(
+            description.restricted_character,
+            description.first_number,
+            description.second_number,
+        )
+    }
+}
+
 impl FromStr for OccurrenceRestrictedPasswordPolicy {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match POLICY_REGEX.captures(s) {
-            Some(captures) => Ok(OccurrenceRestrictedPasswordPolicy::new(
-                captures.get(3).unwrap().as_str().parse()?,
-                captures.get(1).unwrap().as_str().parse()?,
-                captures.get(2).unwrap().as_str().parse()?,
-            )),
-            None => Err(anyhow::Error::msg("could not parse Password Policy")),
-        }
+        Ok(OccurrenceRestrictedPasswordPolicy::from(
+            &s.parse::<PasswordPolicyDescription>()?,
+        ))
     }
 }
 
@@ -101,18 +132,29 @@ impl PasswordPolicy for PositionallyRestrictedPasswordPolicy {
     }
 }
 
+impl TryFrom<&PasswordPolicyDescription> for PositionallyRestrictedPasswordPolicy {
+    type Error = anyhow::Error;
+
+    fn try_from(description: &PasswordPolicyDescription) -> Result<Self, Self::Error> {
+        if description.first_number == 0 || description.second_number == 0 {
+            return Err(anyhow::Error::msg(
+                "Password policy positions must start at 1",
+            ));
+        }
+
+        Ok(PositionallyRestrictedPasswordPolicy::new(
+            description.restricted_character,
+            description.first_number as usize,
+            description.second_number as usize,
+        ))
+    }
+}
+
 impl FromStr for PositionallyRestrictedPasswordPolicy {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match POLICY_REGEX.captures(s) {
-            Some(captures) => Ok(PositionallyRestrictedPasswordPolicy::new(
-                captures.get(3).unwrap().as_str().parse()?,
-                captures.get(1).unwrap().as_str().parse()?,
-                captures.get(2).unwrap().as_str().parse()?,
-            )),
-            None => Err(anyhow::Error::msg("could not parse Password Policy")),
-        }
+        PositionallyRestrictedPasswordPolicy::try_from(&s.parse::<PasswordPolicyDescription>()?)
     }
 }
 
@@ -139,17 +181,22 @@ impl FromStr for Password {
     }
 }
 
-pub fn to_policy_and_password<
-    S: AsRef<str>,
-    Policy: PasswordPolicy + FromStr<Err = anyhow::Error>,
->(
+pub fn to_policy_and_password<S: AsRef<str>, Policy: FromStr<Err = anyhow::Error>>(
     s: S,
 ) -> anyhow::Result<(Policy, Password)> {
-    let splits: Vec<&str> = s.as_ref().split(": ").take(2).collect();
-    let policy: Policy = splits.get(0).unwrap().parse()?;
-    let password: Password = splits.get(1).unwrap().parse()?;
+    let mut splits = s.as_ref().split(": ");
+    match (splits.next(), splits.next()) {
+        (Some(policy), Some(password)) => {
+            let policy: Policy = policy.parse()?;
+            let password: Password = password.parse()?;
 
-    Ok((policy, password))
+            Ok((policy, password))
+        }
+        _ => Err(anyhow::Error::msg(format!(
+            "Could not find password after policy in '{}'",
+            s.as_ref()
+        ))),
+    }
 }
 
 pub fn count_policies_satisfied_by_passwords<Policy: PasswordPolicy>(
@@ -161,6 +208,60 @@ pub fn count_policies_satisfied_by_passwords<Policy: PasswordPolicy>(
         .count()
 }
 
+fn occurrence_restricted_policies_and_passwords(
+    descriptions_and_passwords: Vec<(PasswordPolicyDescription, Password)>,
+) -> Vec<(OccurrenceRestrictedPasswordPolicy, Password)> {
+    descriptions_and_passwords
+        .into_iter()
+        .map(|(description, password)| {
+            (
+                OccurrenceRestrictedPasswordPolicy::from(&description),
+                password,
+            )
+        })
+        .collect()
+}
+
+fn positionally_restricted_policies_and_passwords(
+    descriptions_and_passwords: Vec<(PasswordPolicyDescription, Password)>,
+) -> anyhow::Result<Vec<(PositionallyRestrictedPasswordPolicy, Password)>> {
+    descriptions_and_passwords
+        .into_iter()
+        .map(|(description, password)| {
+            Ok((
+                PositionallyRestrictedPasswordPolicy::try_from(&description)?,
+                password,
+            ))
+        })
+        .collect()
+}
+
+pub struct Day02Solver;
+
+impl Solver for Day02Solver {
+    type Input = Vec<(PasswordPolicyDescription, Password)>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        input.lines().iter().map(to_policy_and_password).collect()
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => Ok(count_policies_satisfied_by_passwords(
+                occurrence_restricted_policies_and_passwords(input),
+            )),
+            ChallengePart::Two => Ok(count_policies_satisfied_by_passwords(
+                positionally_restricted_policies_and_passwords(input)?,
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -228,4 +329,19 @@ mod tests {
         assert_that(&result.0).is_equal_to(PositionallyRestrictedPasswordPolicy::new('a', 1, 3));
         assert_that(&result.1).is_equal_to(Password::new("abcde".to_string()));
     }
+
+    #[test]
+    fn fails_to_convert_string_without_password() {
+        let result = to_policy_and_password::<_, OccurrenceRestrictedPasswordPolicy>("1-3 a");
+
+        assert_that(&result).is_err();
+    }
+
+    #[test]
+    fn fails_to_convert_string_with_position_0() {
+        let result =
+            to_policy_and_password::<_, PositionallyRestrictedPasswordPolicy>("0-3 a: abc");
+
+        assert_that(&result).is_err();
+    }
 }
@@ -1,11 +1,16 @@
 use std::str::FromStr;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 #[derive(Copy, Clone)]
 pub enum MovementDirection {
     Right,
     Down,
 }
 
+#[derive(Copy, Clone)]
 pub enum TravelMapElement {
     Tree,
     Open,
@@ -45,12 +50,13 @@ impl TravelMapPosition {
     }
 }
 
+#[derive(Clone)]
 pub struct TravelMapSegment {
-    map_rows: Vec<String>,
+    map_rows: Vec<Vec<TravelMapElement>>,
 }
 
 impl TravelMapSegment {
-    pub fn new(map_rows: Vec<String>) -> Self {
+    pub fn new(map_rows: Vec<Vec<TravelMapElement>>) -> Self {
         TravelMapSegment { map_rows }
     }
 
@@ -60,13 +66,9 @@ impl TravelMapSegment {
     ) -> anyhow::Result<TravelMapElement> {
         self.map_rows
             .get(position.y())
-            .ok_or_else(|| anyhow::Error::msg("Tried to access position out of map bounds"))?
-            .chars()
-            .nth(position.x())
-            .ok_or_else(|| anyhow::Error::msg("Tried to access position out of map bounds"))?
-            .to_string()
-            .as_str()
-            .parse()
+            .and_then(|map_row| map_row.get(position.x()))
+            .copied()
+            .ok_or_else(|| anyhow::Error::msg("Tried to access position out of map bounds"))
     }
 
     pub fn height(&self) -> usize {
@@ -74,7 +76,7 @@ impl TravelMapSegment {
     }
 
     pub fn width(&self) -> usize {
-        self.map_rows.get(0).unwrap().len()
+        self.map_rows.first().unwrap().len()
     }
 }
 
@@ -150,11 +152,34 @@ impl TobogganRideState {
     }
 }
 
-pub fn count_encountered_trees_for_movement_sequence(
-    map_rows: Vec<String>,
-    movement_sequence: Vec<MovementDirection>,
+fn travel_map_segment_from_rows(map_rows: &[String]) -> anyhow::Result<TravelMapSegment> {
+    let map_rows = map_rows
+        .iter()
+        .map(|map_row| {
+            map_row
+                .chars()
+                .map(|c| c.to_string().as_str().parse())
+                .collect::<anyhow::Result<Vec<TravelMapElement>>>()
+        })
+        .collect::<anyhow::Result<Vec<Vec<TravelMapElement>>>>()?;
+
+    let width = match map_rows.first() {
+        Some(map_row) if !map_row.is_empty() => map_row.len(),
+        _ => return Err(anyhow::Error::msg("Travel map is empty")),
+    };
+    if map_rows.iter().any(|map_row| map_row.len() != width) {
+        return Err(anyhow::Error::msg(
+            "Travel map rows must all be the same width",
+        ));
+    }
+
+    Ok(TravelMapSegment::new(map_rows))
+}
+
+fn count_encountered_trees(
+    map_segment: TravelMapSegment,
+    movement_sequence: &[MovementDirection],
 ) -> u64 {
-    let map_segment = TravelMapSegment::new(map_rows);
     let mut ride_state = TobogganRideState::new(
         TravelMapPosition::new(0, 0),
         TravelMapReader::new(map_segment),
@@ -162,7 +187,7 @@ pub fn count_encountered_trees_for_movement_sequence(
     let mut tree_count = 0;
 
     while !ride_state.at_end_of_map() {
-        for &direction in &movement_sequence {
+        for &direction in movement_sequence {
             if ride_state.travel(direction).is_err() {
                 break;
             }
@@ -177,21 +202,69 @@ pub fn count_encountered_trees_for_movement_sequence(
     tree_count
 }
 
-pub fn product_of_tree_encounters_for_movement_sequences(
-    map_rows: Vec<String>,
-    movement_sequences: Vec<Vec<MovementDirection>>,
+fn product_of_tree_encounters(
+    map_segment: TravelMapSegment,
+    movement_sequences: &[Vec<MovementDirection>],
 ) -> u64 {
     movement_sequences
         .iter()
-        .map(|movement_sequence| {
-            count_encountered_trees_for_movement_sequence(
-                map_rows.clone(),
-                movement_sequence.clone(),
-            )
-        })
+        .map(|movement_sequence| count_encountered_trees(map_segment.clone(), movement_sequence))
         .product()
 }
 
+pub fn count_encountered_trees_for_movement_sequence(
+    map_rows: Vec<String>,
+    movement_sequence: Vec<MovementDirection>,
+) -> anyhow::Result<u64> {
+    Ok(count_encountered_trees(
+        travel_map_segment_from_rows(&map_rows)?,
+        &movement_sequence,
+    ))
+}
+
+pub fn product_of_tree_encounters_for_movement_sequences(
+    map_rows: Vec<String>,
+    movement_sequences: Vec<Vec<MovementDirection>>,
+) -> anyhow::Result<u64> {
+    Ok(product_of_tree_encounters(
+        travel_map_segment_from_rows(&map_rows)?,
+        &movement_sequences,
+    ))
+}
+
+pub struct Day03Solver;
+
+impl Solver for Day03Solver {
+    type Input = TravelMapSegment;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        travel_map_segment_from_rows(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        use MovementDirection::{Down, Right};
+
+        match part {
+            ChallengePart::One => Ok(count_encountered_trees(input, &[Right, Right, Right, Down])),
+            ChallengePart::Two => Ok(product_of_tree_encounters(
+                input,
+                &[
+                    vec![Right, Down],
+                    vec![Right, Right, Right, Down],
+                    vec![Right, Right, Right, Right, Right, Down],
+                    vec![Right, Right, Right, Right, Right, Right, Right, Down],
+                    vec![Right, Down, Down],
+                ],
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -202,7 +275,7 @@ mod tests {
 
     #[test]
     fn counts_hit_trees_along_a_repeated_movement_sequence() {
-        let map_rows = vec![
+        let map_rows = [
             "..##.......",
             "#...#...#..",
             ".#....#..#.",
@@ -219,16 +292,19 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_that(&count_encountered_trees_for_movement_sequence(
-            map_rows,
-            vec![Right, Right, Right, Down],
-        ))
+        assert_that(
+            &count_encountered_trees_for_movement_sequence(
+                map_rows,
+                vec![Right, Right, Right, Down],
+            )
+            .unwrap(),
+        )
         .is_equal_to(7)
     }
 
     #[test]
     fn calculates_product_of_hit_trees_along_repeated_movement_sequences() {
-        let map_rows = vec![
+        let map_rows = [
             "..##.......",
             "#...#...#..",
             ".#....#..#.",
@@ -252,10 +328,10 @@ mod tests {
             vec![Right, Down, Down],
         ];
 
-        assert_that(&product_of_tree_encounters_for_movement_sequences(
-            map_rows,
-            movement_sequences,
-        ))
+        assert_that(
+            &product_of_tree_encounters_for_movement_sequences(map_rows, movement_sequences)
+                .unwrap(),
+        )
         .is_equal_to(2 * 7 * 3 * 4 * 2)
     }
 }
@@ -1,10 +1,14 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::challenge::ChallengePart;
 use crate::day_04::EyeColour::{Amber, Blue, Brown, Green, Grey, Hazel, Other};
 use crate::day_04::HeightUnit::{Centimetres, Inches};
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
 
 #[allow(dead_code)]
 struct RelaxedValidationPassport {
@@ -19,6 +23,7 @@ struct RelaxedValidationPassport {
 }
 
 impl RelaxedValidationPassport {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         ecl: String,
         pid: String,
@@ -42,12 +47,9 @@ impl RelaxedValidationPassport {
     }
 }
 
-impl FromStr for RelaxedValidationPassport {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl RelaxedValidationPassport {
+    fn from_fields(passport_reader: &PassportReader) -> anyhow::Result<Self> {
         let missing_required_field_error_message = "Could not find required field in passport text";
-        let passport_reader = PassportReader::new(s.to_string());
 
         Ok(RelaxedValidationPassport::new(
             passport_reader
@@ -237,6 +239,7 @@ struct StrictValidationPassport {
 }
 
 impl StrictValidationPassport {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         ecl: EyeColour,
         pid: PassportId,
@@ -270,12 +273,9 @@ impl StrictValidationPassport {
     }
 }
 
-impl FromStr for StrictValidationPassport {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl StrictValidationPassport {
+    fn from_fields(passport_reader: &PassportReader) -> anyhow::Result<Self> {
         let missing_required_field_error_message = "Could not find required field in passport text";
-        let passport_reader = PassportReader::new(s.to_string());
 
         Ok(StrictValidationPassport::new(
             passport_reader
@@ -311,43 +311,104 @@ impl FromStr for StrictValidationPassport {
     }
 }
 
-struct PassportReader {
-    text: String,
+pub struct PassportReader {
+    fields: HashMap<String, String>,
 }
 
 impl PassportReader {
-    fn new(text: String) -> Self {
-        PassportReader { text }
+    fn get_field(&self, field_name: &str) -> Option<String> {
+        self.fields.get(field_name).cloned()
     }
+}
 
-    fn get_field(&self, field_name: &str) -> Option<String> {
-        let re = Regex::new(format!(r"{}:(\S+)", field_name).as_str()).unwrap();
-        match re.captures(self.text.as_str()) {
-            Some(captures) => Some(captures.get(1).unwrap().as_str().to_string()),
-            None => None,
+impl FromStr for PassportReader {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref FIELD_RE: Regex = Regex::new(r"^(?P<name>[^:]+):(?P<value>\S+)$").unwrap();
         }
+
+        let fields = s
+            .split_whitespace()
+            .map(|field| match FIELD_RE.captures(field) {
+                Some(captures) => Ok((
+                    captures.name("name").unwrap().as_str().to_string(),
+                    captures.name("value").unwrap().as_str().to_string(),
+                )),
+                None => Err(anyhow::Error::msg(format!(
+                    "Could not parse passport field '{}'",
+                    field
+                ))),
+            })
+            .collect::<anyhow::Result<HashMap<String, String>>>()?;
+
+        Ok(PassportReader { fields })
     }
 }
 
-pub fn count_valid_relaxed_validation_passports_in_text(passport_strings: Vec<String>) -> usize {
-    passport_strings
+fn passport_readers_from_strings(
+    passport_strings: &[String],
+) -> anyhow::Result<Vec<PassportReader>> {
+    passport_strings.iter().map(|s| s.parse()).collect()
+}
+
+fn count_valid_relaxed_validation_passports(passport_readers: &[PassportReader]) -> usize {
+    passport_readers
         .iter()
-        .filter(|passport_string| {
-            RelaxedValidationPassport::from_str(passport_string.as_str()).is_ok()
-        })
+        .filter(|passport_reader| RelaxedValidationPassport::from_fields(passport_reader).is_ok())
         .count()
 }
 
-pub fn count_valid_strict_validation_passports_in_text(passport_strings: Vec<String>) -> usize {
-    passport_strings
+fn count_valid_strict_validation_passports(passport_readers: &[PassportReader]) -> usize {
+    passport_readers
         .iter()
-        .filter(|passport_string| {
-            let passport = StrictValidationPassport::from_str(passport_string.as_str());
-            passport.is_ok() && passport.unwrap().is_valid()
+        .filter(|passport_reader| {
+            StrictValidationPassport::from_fields(passport_reader)
+                .map(|passport| passport.is_valid())
+                .unwrap_or(false)
         })
         .count()
 }
 
+pub fn count_valid_relaxed_validation_passports_in_text(
+    passport_strings: Vec<String>,
+) -> anyhow::Result<usize> {
+    Ok(count_valid_relaxed_validation_passports(
+        &passport_readers_from_strings(&passport_strings)?,
+    ))
+}
+
+pub fn count_valid_strict_validation_passports_in_text(
+    passport_strings: Vec<String>,
+) -> anyhow::Result<usize> {
+    Ok(count_valid_strict_validation_passports(
+        &passport_readers_from_strings(&passport_strings)?,
+    ))
+}
+
+pub struct Day04Solver;
+
+impl Solver for Day04Solver {
+    type Input = Vec<PassportReader>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        passport_readers_from_strings(&input.records())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => Ok(count_valid_relaxed_validation_passports(&input)),
+            ChallengePart::Two => Ok(count_valid_strict_validation_passports(&input)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -356,7 +417,7 @@ mod tests {
 
     #[test]
     fn counts_valid_relaxed_validation_passports() {
-        let passport_strings: Vec<String> = vec![
+        let passport_strings: Vec<String> = [
             "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\nbyr:1937 iyr:2017 cid:147 hgt:183cm",
             "iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884\nhcl:#cfa07d byr:1929",
             "hcl:#ae17e1 iyr:2013\neyr:2024\necl:brn pid:760753108 byr:1931\nhgt:179cm",
@@ -366,15 +427,13 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_that(&count_valid_relaxed_validation_passports_in_text(
-            passport_strings,
-        ))
-        .is_equal_to(2);
+        assert_that(&count_valid_relaxed_validation_passports_in_text(passport_strings).unwrap())
+            .is_equal_to(2);
     }
 
     #[test]
     fn recognises_invalid_strict_validation_passports() {
-        let passport_strings: Vec<String> = vec![
+        let passport_strings: Vec<String> = [
             "eyr:1972 cid:100\nhcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926",
             "iyr:2019\nhcl:#602927 eyr:1967 hgt:170cm\necl:grn pid:012533040 byr:1946",
             "hcl:dab227 iyr:2012\necl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277",
@@ -384,15 +443,13 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_that(&count_valid_strict_validation_passports_in_text(
-            passport_strings,
-        ))
-        .is_equal_to(0);
+        assert_that(&count_valid_strict_validation_passports_in_text(passport_strings).unwrap())
+            .is_equal_to(0);
     }
 
     #[test]
     fn recognises_valid_strict_validation_passports() {
-        let passport_strings: Vec<String> = vec![
+        let passport_strings: Vec<String> = [
             "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\nhcl:#623a2f",
             "eyr:2029 ecl:blu cid:129 byr:1989\niyr:2014 pid:896056539 hcl:#a97842 hgt:165cm",
             "hcl:#888785\nhgt:164cm byr:2001 iyr:2015 cid:88\npid:545766238 ecl:hzl\neyr:2022",
@@ -402,9 +459,7 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_that(&count_valid_strict_validation_passports_in_text(
-            passport_strings,
-        ))
-        .is_equal_to(4);
+        assert_that(&count_valid_strict_validation_passports_in_text(passport_strings).unwrap())
+            .is_equal_to(4);
     }
 }
@@ -3,6 +3,11 @@ use std::str::FromStr;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 struct PlaneSpecification {
     rows: u32,
     columns: u32,
@@ -32,7 +37,7 @@ impl PlaneSpecification {
 
 #[cfg_attr(test, derive(Debug))]
 #[derive(Ord, PartialOrd, Eq, PartialEq)]
-struct SeatId {
+pub struct SeatId {
     value: u32,
 }
 
@@ -239,21 +244,20 @@ fn seat_ids(seat_code_strings: Vec<String>) -> anyhow::Result<Vec<SeatId>> {
         .collect())
 }
 
-pub fn find_highest_seat_id_on_plane(seat_code_strings: Vec<String>) -> anyhow::Result<u32> {
-    Ok(seat_ids(seat_code_strings)?
+fn highest_seat_id(seat_ids: &[SeatId]) -> anyhow::Result<u32> {
+    Ok(seat_ids
         .iter()
         .max()
         .ok_or_else(|| anyhow::Error::msg("Empty list of seat codes"))?
         .value())
 }
 
-pub fn find_my_empty_seat_id(seat_code_strings: Vec<String>) -> anyhow::Result<u32> {
-    let mut sorted_seat_ids = seat_ids(seat_code_strings)?;
+fn my_empty_seat_id(mut sorted_seat_ids: Vec<SeatId>) -> anyhow::Result<u32> {
     sorted_seat_ids.sort();
 
     for (i, seat_id) in sorted_seat_ids
-        .get(1..sorted_seat_ids.len() - 2)
-        .unwrap()
+        .get(1..sorted_seat_ids.len().saturating_sub(2))
+        .unwrap_or_default()
         .iter()
         .enumerate()
     {
@@ -262,7 +266,39 @@ pub fn find_my_empty_seat_id(seat_code_strings: Vec<String>) -> anyhow::Result<u
         }
     }
 
-    Err(anyhow::Error::msg("Did not find my seat"))
+    Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+        "Did not find my seat".to_string(),
+    )))
+}
+
+pub fn find_highest_seat_id_on_plane(seat_code_strings: Vec<String>) -> anyhow::Result<u32> {
+    highest_seat_id(&seat_ids(seat_code_strings)?)
+}
+
+pub fn find_my_empty_seat_id(seat_code_strings: Vec<String>) -> anyhow::Result<u32> {
+    my_empty_seat_id(seat_ids(seat_code_strings)?)
+}
+
+pub struct Day05Solver;
+
+impl Solver for Day05Solver {
+    type Input = Vec<SeatId>;
+    type Answer = u32;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        seat_ids(input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => highest_seat_id(&input),
+            ChallengePart::Two => my_empty_seat_id(input),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,15 @@
 use std::collections::hash_set::HashSet;
 use std::iter::FromIterator;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 struct Answer(char);
 
 #[derive(Debug)]
-struct IndividualMemberPositiveAnswerSet {
+pub struct IndividualMemberPositiveAnswerSet {
     answers: HashSet<Answer>,
 }
 
@@ -31,9 +35,8 @@ impl TravelGroupPositiveAnswerSet {
             answers: HashSet::from_iter(
                 individual_member_answer_sets
                     .iter()
-                    .map(IndividualMemberPositiveAnswerSet::answers)
-                    .cloned()
-                    .flatten(),
+                    .flat_map(IndividualMemberPositiveAnswerSet::answers)
+                    .cloned(),
             ),
         }
     }
@@ -47,9 +50,8 @@ impl TravelGroupPositiveAnswerSet {
             .fold(
                 individual_member_answer_sets
                     .first()
-                    .unwrap()
-                    .answers()
-                    .clone(),
+                    .map(|answer_set| answer_set.answers().clone())
+                    .unwrap_or_default(),
                 |accumulator, answers| accumulator.intersection(answers).cloned().collect(),
             );
 
@@ -74,24 +76,65 @@ fn travel_group_answer_sets_from_string<S: AsRef<str>>(
         .collect()
 }
 
-pub fn count_total_group_unified_positive_answers(answer_groups: Vec<String>) -> usize {
+fn travel_group_answer_sets_from_strings(
+    answer_groups: &[String],
+) -> Vec<Vec<IndividualMemberPositiveAnswerSet>> {
     answer_groups
         .iter()
         .map(travel_group_answer_sets_from_string)
+        .collect()
+}
+
+fn total_group_unified_positive_answers(
+    group_answer_sets: Vec<Vec<IndividualMemberPositiveAnswerSet>>,
+) -> usize {
+    group_answer_sets
+        .into_iter()
         .map(TravelGroupPositiveAnswerSet::from_unifying_individual_member_answer_sets)
         .map(|group_set| group_set.len())
         .sum()
 }
 
-pub fn count_total_group_intersecting_positive_answers(answer_groups: Vec<String>) -> usize {
-    answer_groups
-        .iter()
-        .map(travel_group_answer_sets_from_string)
+fn total_group_intersecting_positive_answers(
+    group_answer_sets: Vec<Vec<IndividualMemberPositiveAnswerSet>>,
+) -> usize {
+    group_answer_sets
+        .into_iter()
         .map(TravelGroupPositiveAnswerSet::from_intersecting_individual_member_answer_sets)
         .map(|group_set| group_set.len())
         .sum()
 }
 
+pub fn count_total_group_unified_positive_answers(answer_groups: Vec<String>) -> usize {
+    total_group_unified_positive_answers(travel_group_answer_sets_from_strings(&answer_groups))
+}
+
+pub fn count_total_group_intersecting_positive_answers(answer_groups: Vec<String>) -> usize {
+    total_group_intersecting_positive_answers(travel_group_answer_sets_from_strings(&answer_groups))
+}
+
+pub struct Day06Solver;
+
+impl Solver for Day06Solver {
+    type Input = Vec<Vec<IndividualMemberPositiveAnswerSet>>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        Ok(travel_group_answer_sets_from_strings(&input.records()))
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => Ok(total_group_unified_positive_answers(input)),
+            ChallengePart::Two => Ok(total_group_intersecting_positive_answers(input)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -100,7 +143,7 @@ mod tests {
 
     #[test]
     fn counts_total_group_unified_positive_answers() {
-        let answer_groups: Vec<String> = vec!["abc", "a\nb\nc", "ab\nac", "a\na\na\na", "b"]
+        let answer_groups: Vec<String> = ["abc", "a\nb\nc", "ab\nac", "a\na\na\na", "b"]
             .iter()
             .map(ToString::to_string)
             .collect();
@@ -110,7 +153,7 @@ mod tests {
 
     #[test]
     fn counts_total_group_intersecting_positive_answers() {
-        let answer_groups: Vec<String> = vec!["abc", "a\nb\nc", "ab\nac", "a\na\na\na", "b"]
+        let answer_groups: Vec<String> = ["abc", "a\nb\nc", "ab\nac", "a\na\na\na", "b"]
             .iter()
             .map(ToString::to_string)
             .collect();
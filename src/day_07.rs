@@ -1,11 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use petgraph::prelude::{Dfs, NodeIndex};
+use petgraph::visit::EdgeRef;
 use petgraph::Graph;
 use regex::Regex;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 struct Bag(String);
 
@@ -18,21 +23,25 @@ impl Bag {
 #[cfg_attr(test, derive(Debug))]
 struct BagQuantity {
     bag: Bag,
-    _count: usize,
+    count: usize,
 }
 
 impl BagQuantity {
     fn new(bag: Bag, count: usize) -> Self {
-        BagQuantity { bag, _count: count }
+        BagQuantity { bag, count }
     }
 
     fn bag(&self) -> &Bag {
         &self.bag
     }
+
+    fn count(&self) -> usize {
+        self.count
+    }
 }
 
 #[cfg_attr(test, derive(Debug))]
-struct BagContainerRule {
+pub struct BagContainerRule {
     bag: Bag,
     contained: Vec<BagQuantity>,
 }
@@ -68,20 +77,15 @@ impl FromStr for BagContainerRule {
                 .to_string(),
         );
 
-        let contained_bags: Vec<BagQuantity> = CHILD_STYLE_REGEX
+        let contained_bags = CHILD_STYLE_REGEX
             .captures_iter(s)
             .map(|captures| {
-                BagQuantity::new(
+                Ok(BagQuantity::new(
                     Bag::new(captures.name("style").unwrap().as_str().to_string()),
-                    captures
-                        .name("style_count")
-                        .unwrap()
-                        .as_str()
-                        .parse()
-                        .unwrap(),
-                )
+                    captures.name("style_count").unwrap().as_str().parse()?,
+                ))
             })
-            .collect();
+            .collect::<anyhow::Result<Vec<BagQuantity>>>()?;
 
         Ok(BagContainerRule {
             bag: parent_bag,
@@ -99,9 +103,9 @@ impl BagRuleWalker {
         BagRuleWalker { rules }
     }
 
-    fn build_rule_graph(&self) -> (Graph<Bag, u32>, HashMap<Bag, NodeIndex>) {
+    fn build_rule_graph(&self) -> (Graph<Bag, usize>, HashMap<Bag, NodeIndex>) {
         let mut bag_node_indexes: HashMap<Bag, NodeIndex> = HashMap::new();
-        let mut graph: Graph<Bag, u32> = Graph::new();
+        let mut graph: Graph<Bag, usize> = Graph::new();
 
         self.rules.iter().for_each(|rule| {
             let parent = rule.parent();
@@ -118,7 +122,7 @@ impl BagRuleWalker {
                 graph.add_edge(
                     *bag_node_indexes.get(parent).unwrap(),
                     *bag_node_indexes.get(bag).unwrap(),
-                    1,
+                    quantity.count(),
                 );
             });
         });
@@ -126,33 +130,123 @@ impl BagRuleWalker {
         (graph, bag_node_indexes)
     }
 
-    fn count_bags_that_eventually_contain(&self, bag: Bag) -> usize {
+    fn count_bags_that_eventually_contain(&self, bag: Bag) -> anyhow::Result<usize> {
         let (mut graph, bag_node_indexes) = self.build_rule_graph();
 
         let mut count = 0;
 
         graph.reverse();
 
-        let mut search = Dfs::new(&graph, *bag_node_indexes.get(&bag).unwrap());
+        let node = *bag_node_indexes
+            .get(&bag)
+            .ok_or_else(|| anyhow::Error::msg("Could not find bag in rules"))?;
+        let mut search = Dfs::new(&graph, node);
         while let Some(_node) = search.next(&graph) {
             count += 1;
         }
 
-        count - 1
+        Ok(count - 1)
+    }
+
+    fn count_bags_contained_inside(&self, bag: Bag) -> anyhow::Result<usize> {
+        let (graph, bag_node_indexes) = self.build_rule_graph();
+
+        let node = *bag_node_indexes
+            .get(&bag)
+            .ok_or_else(|| anyhow::Error::msg("Could not find bag in rules"))?;
+
+        Self::count_bags_contained_inside_node(
+            &graph,
+            node,
+            &mut HashSet::new(),
+            &mut HashMap::new(),
+        )
+    }
+
+    fn count_bags_contained_inside_node(
+        graph: &Graph<Bag, usize>,
+        node: NodeIndex,
+        nodes_on_path: &mut HashSet<NodeIndex>,
+        known_counts: &mut HashMap<NodeIndex, usize>,
+    ) -> anyhow::Result<usize> {
+        if let Some(count) = known_counts.get(&node) {
+            return Ok(*count);
+        }
+        if !nodes_on_path.insert(node) {
+            return Err(anyhow::Error::msg(format!(
+                "Bag rules contain a cycle through {} bags",
+                graph[node].0
+            )));
+        }
+
+        let overflow_error =
+            || anyhow::Error::msg("Bag count exceeded the maximum supported value");
+        let count = graph.edges(node).try_fold(0_usize, |count, edge| {
+            let contained_count = Self::count_bags_contained_inside_node(
+                graph,
+                edge.target(),
+                nodes_on_path,
+                known_counts,
+            )?;
+
+            contained_count
+                .checked_add(1)
+                .and_then(|bags| bags.checked_mul(*edge.weight()))
+                .and_then(|bags| bags.checked_add(count))
+                .ok_or_else(overflow_error)
+        })?;
+
+        nodes_on_path.remove(&node);
+        known_counts.insert(node, count);
+        Ok(count)
     }
 }
 
+fn bag_rules_from_strings(bag_rule_strings: &[String]) -> anyhow::Result<Vec<BagContainerRule>> {
+    bag_rule_strings.iter().map(|s| s.parse()).collect()
+}
+
 pub fn count_bags_that_eventually_contain(
     bag_rule_strings: Vec<String>,
     bag_style: &str,
 ) -> anyhow::Result<usize> {
-    let bag_rules: Vec<BagContainerRule> = bag_rule_strings
-        .iter()
-        .map(|s| s.parse())
-        .collect::<anyhow::Result<Vec<BagContainerRule>>>()?;
-    let bag_rule_walker = BagRuleWalker::new(bag_rules);
+    let bag_rule_walker = BagRuleWalker::new(bag_rules_from_strings(&bag_rule_strings)?);
+
+    bag_rule_walker.count_bags_that_eventually_contain(Bag::new(bag_style.to_string()))
+}
+
+pub fn count_bags_contained_inside(
+    bag_rule_strings: Vec<String>,
+    bag_style: &str,
+) -> anyhow::Result<usize> {
+    let bag_rule_walker = BagRuleWalker::new(bag_rules_from_strings(&bag_rule_strings)?);
+
+    bag_rule_walker.count_bags_contained_inside(Bag::new(bag_style.to_string()))
+}
+
+pub struct Day07Solver;
 
-    Ok(bag_rule_walker.count_bags_that_eventually_contain(Bag::new(bag_style.to_string())))
+impl Solver for Day07Solver {
+    type Input = Vec<BagContainerRule>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        bag_rules_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let bag_rule_walker = BagRuleWalker::new(input);
+        let bag = Bag::new("shiny gold".to_string());
+
+        match part {
+            ChallengePart::One => bag_rule_walker.count_bags_that_eventually_contain(bag),
+            ChallengePart::Two => bag_rule_walker.count_bags_contained_inside(bag),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -163,7 +257,7 @@ mod tests {
 
     #[test]
     fn counts_bags_that_eventually_contain_a_bag() {
-        let rules: Vec<String> = vec![
+        let rules: Vec<String> = [
             "light red bags contain 1 bright white bag, 2 muted yellow bags.",
             "dark orange bags contain 3 bright white bags, 4 muted yellow bags.",
             "bright white bags contain 1 shiny gold bag.",
@@ -181,4 +275,35 @@ mod tests {
         assert_that(&count_bags_that_eventually_contain(rules, "shiny gold").unwrap())
             .is_equal_to(4);
     }
+
+    #[test]
+    fn counts_bags_contained_inside_a_bag() {
+        let rules: Vec<String> = [
+            "shiny gold bags contain 2 dark red bags.",
+            "dark red bags contain 2 dark orange bags.",
+            "dark orange bags contain 2 dark yellow bags.",
+            "dark yellow bags contain 2 dark green bags.",
+            "dark green bags contain 2 dark blue bags.",
+            "dark blue bags contain 2 dark violet bags.",
+            "dark violet bags contain no other bags.",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_that(&count_bags_contained_inside(rules, "shiny gold").unwrap()).is_equal_to(126);
+    }
+
+    #[test]
+    fn fails_to_count_bags_contained_inside_a_bag_with_cyclic_rules() {
+        let rules: Vec<String> = [
+            "shiny gold bags contain 2 dark red bags.",
+            "dark red bags contain 1 shiny gold bag.",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_that(&count_bags_contained_inside(rules, "shiny gold").is_err()).is_true();
+    }
 }
@@ -3,6 +3,11 @@ use std::str::FromStr;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 #[derive(Copy, Clone)]
 enum BootOperation {
     Jump,
@@ -27,7 +32,7 @@ impl FromStr for BootOperation {
 }
 
 #[derive(Copy, Clone)]
-struct BootInstruction {
+pub struct BootInstruction {
     operation: BootOperation,
     argument: i64,
 }
@@ -188,24 +193,33 @@ impl BootDebugger {
     }
 }
 
+fn boot_instructions_from_strings(
+    boot_instruction_strings: &[String],
+) -> anyhow::Result<Vec<BootInstruction>> {
+    boot_instruction_strings
+        .iter()
+        .map(|instruction_string| instruction_string.parse())
+        .collect()
+}
+
 pub fn get_accumulator_value_before_repeated_instruction(
     boot_instruction_strings: Vec<String>,
 ) -> anyhow::Result<i64> {
-    let boot_instructions = boot_instruction_strings
-        .iter()
-        .map(|instruction_string| instruction_string.parse())
-        .collect::<anyhow::Result<Vec<BootInstruction>>>()?;
-    Ok(BootDebugger::new(boot_instructions).get_accumulator_value_before_repeated_instruction()?)
+    let boot_instructions = boot_instructions_from_strings(&boot_instruction_strings)?;
+    BootDebugger::new(boot_instructions).get_accumulator_value_before_repeated_instruction()
 }
 
 pub fn get_accumulator_value_after_termination_of_fixed_instructions(
     boot_instruction_strings: Vec<String>,
 ) -> anyhow::Result<i64> {
-    let boot_instructions = boot_instruction_strings
-        .iter()
-        .map(|instruction_string| instruction_string.parse())
-        .collect::<anyhow::Result<Vec<BootInstruction>>>()?;
+    accumulator_value_after_termination_of_fixed_instructions(boot_instructions_from_strings(
+        &boot_instruction_strings,
+    )?)
+}
 
+fn accumulator_value_after_termination_of_fixed_instructions(
+    boot_instructions: Vec<BootInstruction>,
+) -> anyhow::Result<i64> {
     for (i, boot_instruction) in boot_instructions.iter().enumerate() {
         let result = match boot_instruction.operation() {
             BootOperation::Jump => {
@@ -228,9 +242,33 @@ pub fn get_accumulator_value_after_termination_of_fixed_instructions(
         }
     }
 
-    Err(anyhow::Error::msg(
-        "Did not find fixed version of instructions",
-    ))
+    Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+        "Did not find fixed version of instructions".to_string(),
+    )))
+}
+
+pub struct Day08Solver;
+
+impl Solver for Day08Solver {
+    type Input = Vec<BootInstruction>;
+    type Answer = i64;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        boot_instructions_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => {
+                BootDebugger::new(input).get_accumulator_value_before_repeated_instruction()
+            }
+            ChallengePart::Two => accumulator_value_after_termination_of_fixed_instructions(input),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -241,7 +279,7 @@ mod tests {
 
     #[test]
     fn gets_accumulator_value_before_repeated_instruction() {
-        let boot_instruction_strings = vec![
+        let boot_instruction_strings = [
             "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4",
             "acc +6",
         ]
@@ -257,7 +295,7 @@ mod tests {
 
     #[test]
     fn gets_accumulator_value_after_termination_of_fixed_instructions() {
-        let boot_instruction_strings = vec![
+        let boot_instruction_strings = [
             "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4",
             "acc +6",
         ]
@@ -1,6 +1,11 @@
 use std::cmp::{max, min, Ordering};
 use std::num::ParseIntError;
 
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 struct XMASDecrypter;
 
 impl XMASDecrypter {
@@ -11,7 +16,7 @@ impl XMASDecrypter {
     fn contains_pair_that_sums_to(&self, xmas_encrypted_messages: &[u64], target: &u64) -> bool {
         for (outer_index, a) in xmas_encrypted_messages.iter().enumerate() {
             for (inner_index, b) in xmas_encrypted_messages.iter().enumerate() {
-                if outer_index != inner_index && a + b == *target {
+                if outer_index != inner_index && a.checked_add(*b) == Some(*target) {
                     return true;
                 }
             }
@@ -40,7 +45,9 @@ impl XMASDecrypter {
             });
 
         match result {
-            Ok(_) => Err(anyhow::Error::msg("Did not find weakness")),
+            Ok(_) => Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+                "Did not find weakness".to_string(),
+            ))),
             Err(e) => Ok(*e),
         }
     }
@@ -54,7 +61,7 @@ impl XMASDecrypter {
             self.find_first_encoding_error_instance(xmas_encrypted_messages, preamble_length)?;
 
         for (outer_index, first) in xmas_encrypted_messages.iter().enumerate() {
-            let mut accumulator = 0;
+            let mut accumulator: u64 = 0;
             let mut smallest = *first;
             let mut largest = *first;
 
@@ -63,7 +70,10 @@ impl XMASDecrypter {
                 .enumerate()
                 .skip_while(|(i, _n)| i < &outer_index)
             {
-                accumulator += n;
+                accumulator = match accumulator.checked_add(*n) {
+                    Some(accumulator) => accumulator,
+                    None => break,
+                };
                 smallest = min(smallest, *n);
                 largest = max(largest, *n);
 
@@ -75,18 +85,27 @@ impl XMASDecrypter {
             }
         }
 
-        Err(anyhow::Error::msg("Could not find encryption weakness"))
+        Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+            "Could not find encryption weakness".to_string(),
+        )))
     }
 }
 
+fn xmas_encrypted_messages_from_strings(
+    xmas_encrypted_message_strings: &[String],
+) -> Result<Vec<u64>, ParseIntError> {
+    xmas_encrypted_message_strings
+        .iter()
+        .map(|s| s.parse())
+        .collect()
+}
+
 pub fn find_first_xmas_encoding_error(
     xmas_encrypted_message_strings: Vec<String>,
     preamble_length: usize,
 ) -> anyhow::Result<u64> {
-    let xmas_encrypted_messages: Vec<u64> = xmas_encrypted_message_strings
-        .iter()
-        .map(|s| s.parse())
-        .collect::<Result<Vec<u64>, ParseIntError>>()?;
+    let xmas_encrypted_messages =
+        xmas_encrypted_messages_from_strings(&xmas_encrypted_message_strings)?;
     let xmas_decrypter = XMASDecrypter::new();
 
     xmas_decrypter.find_first_encoding_error_instance(&xmas_encrypted_messages, preamble_length)
@@ -96,15 +115,37 @@ pub fn get_encryption_weakness(
     xmas_encrypted_message_strings: Vec<String>,
     preamble_length: usize,
 ) -> anyhow::Result<u64> {
-    let xmas_encrypted_messages: Vec<u64> = xmas_encrypted_message_strings
-        .iter()
-        .map(|s| s.parse())
-        .collect::<Result<Vec<u64>, ParseIntError>>()?;
+    let xmas_encrypted_messages =
+        xmas_encrypted_messages_from_strings(&xmas_encrypted_message_strings)?;
     let xmas_decrypter = XMASDecrypter::new();
 
     xmas_decrypter.get_encryption_weakness(&xmas_encrypted_messages, preamble_length)
 }
 
+pub struct Day09Solver;
+
+impl Solver for Day09Solver {
+    type Input = Vec<u64>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        Ok(xmas_encrypted_messages_from_strings(&input.lines())?)
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let xmas_decrypter = XMASDecrypter::new();
+
+        match part {
+            ChallengePart::One => xmas_decrypter.find_first_encoding_error_instance(&input, 25),
+            ChallengePart::Two => xmas_decrypter.get_encryption_weakness(&input, 25),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -1,6 +1,11 @@
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 struct Joltage(i64);
 
@@ -21,7 +26,7 @@ impl Sub for Joltage {
 }
 
 #[derive(Copy, Clone)]
-struct JoltageAdapter {
+pub struct JoltageAdapter {
     output_joltage: Joltage,
 }
 
@@ -66,12 +71,11 @@ impl JoltageAdapterChain {
             .map(JoltageAdapter::output_joltage)
             .enumerate()
             .map(|(index, joltage)| {
-                let previous_joltage;
-                if index == 0 {
-                    previous_joltage = Joltage(0);
+                let previous_joltage = if index == 0 {
+                    Joltage(0)
                 } else {
-                    previous_joltage = self.adapters.get(index - 1).unwrap().output_joltage();
-                }
+                    self.adapters.get(index - 1).unwrap().output_joltage()
+                };
                 joltage - previous_joltage
             })
             .collect()
@@ -98,12 +102,18 @@ impl JoltageAdapterChainBuilder {
                 .try_for_each(|adapter| match chain_adapters.last() {
                     Some(previous_adapter) => {
                         if adapter.supports_input_joltage(previous_adapter.output_joltage()) {
-                            Ok(chain_adapters.push(*adapter))
+                            chain_adapters.push(*adapter);
+                            Ok(())
                         } else {
-                            Err(anyhow::Error::msg("Could not use all adapters in chain"))
+                            Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+                                "Could not use all adapters in chain".to_string(),
+                            )))
                         }
                     }
-                    None => Ok(chain_adapters.push(*adapter)),
+                    None => {
+                        chain_adapters.push(*adapter);
+                        Ok(())
+                    }
                 });
 
         match chain_building_result {
@@ -111,6 +121,41 @@ impl JoltageAdapterChainBuilder {
             Err(e) => Err(e),
         }
     }
+
+    fn count_possible_chains_to_adapter(
+        &self,
+        mut joltage_adapters: Vec<JoltageAdapter>,
+        final_adapter: JoltageAdapter,
+    ) -> u64 {
+        joltage_adapters.sort_by_key(|&a| a.output_joltage());
+
+        let outlet_joltage = Joltage(0);
+        let mut chain_counts: Vec<(JoltageAdapter, u64)> = vec![];
+
+        joltage_adapters.iter().for_each(|adapter| {
+            let chains_from_outlet = if adapter.supports_input_joltage(outlet_joltage) {
+                1
+            } else {
+                0
+            };
+
+            let chains_from_previous_adapters: u64 = chain_counts
+                .iter()
+                .filter(|(previous_adapter, _)| {
+                    adapter.supports_input_joltage(previous_adapter.output_joltage())
+                })
+                .map(|(_, count)| count)
+                .sum();
+
+            chain_counts.push((*adapter, chains_from_outlet + chains_from_previous_adapters));
+        });
+
+        chain_counts
+            .iter()
+            .filter(|(adapter, _)| adapter.output_joltage() == final_adapter.output_joltage())
+            .map(|(_, count)| count)
+            .sum()
+    }
 }
 
 fn calculate_built_in_joltage_adapter(joltage_adapters: &[JoltageAdapter]) -> JoltageAdapter {
@@ -123,13 +168,15 @@ fn calculate_built_in_joltage_adapter(joltage_adapters: &[JoltageAdapter]) -> Jo
     JoltageAdapter::new(output_joltage)
 }
 
-pub fn product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in(
-    joltage_adapter_strings: Vec<String>,
+fn joltage_adapters_from_strings(
+    joltage_adapter_strings: &[String],
+) -> anyhow::Result<Vec<JoltageAdapter>> {
+    joltage_adapter_strings.iter().map(|s| s.parse()).collect()
+}
+
+fn product_of_1_and_3_joltage_differences(
+    mut joltage_adapters: Vec<JoltageAdapter>,
 ) -> anyhow::Result<u64> {
-    let mut joltage_adapters = joltage_adapter_strings
-        .iter()
-        .map(|s| s.parse())
-        .collect::<anyhow::Result<Vec<JoltageAdapter>>>()?;
     joltage_adapters.push(calculate_built_in_joltage_adapter(&joltage_adapters));
 
     let joltage_adapter_chain_builder = JoltageAdapterChainBuilder::new();
@@ -150,6 +197,52 @@ pub fn product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in(
     Ok((joltage_difference_of_1_count * joltage_difference_of_3_count) as u64)
 }
 
+fn distinct_joltage_adapter_arrangements(mut joltage_adapters: Vec<JoltageAdapter>) -> u64 {
+    let built_in_joltage_adapter = calculate_built_in_joltage_adapter(&joltage_adapters);
+    joltage_adapters.push(built_in_joltage_adapter);
+
+    let joltage_adapter_chain_builder = JoltageAdapterChainBuilder::new();
+
+    joltage_adapter_chain_builder
+        .count_possible_chains_to_adapter(joltage_adapters, built_in_joltage_adapter)
+}
+
+pub fn product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in(
+    joltage_adapter_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    product_of_1_and_3_joltage_differences(joltage_adapters_from_strings(&joltage_adapter_strings)?)
+}
+
+pub fn count_distinct_joltage_adapter_arrangements(
+    joltage_adapter_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    Ok(distinct_joltage_adapter_arrangements(
+        joltage_adapters_from_strings(&joltage_adapter_strings)?,
+    ))
+}
+
+pub struct Day10Solver;
+
+impl Solver for Day10Solver {
+    type Input = Vec<JoltageAdapter>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        joltage_adapters_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => product_of_1_and_3_joltage_differences(input),
+            ChallengePart::Two => Ok(distinct_joltage_adapter_arrangements(input)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -158,11 +251,10 @@ mod tests {
 
     #[test]
     fn calculates_product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in() {
-        let joltage_adapter_strings =
-            vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"]
-                .iter()
-                .map(ToString::to_string)
-                .collect::<Vec<String>>();
+        let joltage_adapter_strings = ["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
 
         assert_that(
             &product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in(
@@ -172,4 +264,30 @@ mod tests {
         )
         .is_equal_to(35);
     }
+
+    #[test]
+    fn counts_distinct_joltage_adapter_arrangements() {
+        let joltage_adapter_strings = ["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+
+        assert_that(&count_distinct_joltage_adapter_arrangements(joltage_adapter_strings).unwrap())
+            .is_equal_to(8);
+    }
+
+    #[test]
+    fn counts_distinct_joltage_adapter_arrangements_of_larger_adapter_set() {
+        let joltage_adapter_strings = [
+            "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45",
+            "19", "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34",
+            "10", "3",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+
+        assert_that(&count_distinct_joltage_adapter_arrangements(joltage_adapter_strings).unwrap())
+            .is_equal_to(19208);
+    }
 }
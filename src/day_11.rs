@@ -1,13 +1,17 @@
 use std::str::FromStr;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 #[derive(Eq, PartialEq)]
-enum SeatOccupancy {
+pub enum SeatOccupancy {
     Occupied,
     Empty,
 }
 
 #[derive(Eq, PartialEq)]
-enum SeatingElement {
+pub enum SeatingElement {
     Floor,
     Seat(SeatOccupancy),
 }
@@ -51,6 +55,43 @@ impl Direction {
     fn horizontal_component(&self) -> &HorizontalDirectionComponent {
         &self.horizontal_component
     }
+
+    fn all() -> [Self; 8] {
+        [
+            Direction::new(
+                VerticalDirectionComponent::Above,
+                HorizontalDirectionComponent::Equal,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Above,
+                HorizontalDirectionComponent::Right,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Above,
+                HorizontalDirectionComponent::Left,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Below,
+                HorizontalDirectionComponent::Equal,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Below,
+                HorizontalDirectionComponent::Right,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Below,
+                HorizontalDirectionComponent::Left,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Equal,
+                HorizontalDirectionComponent::Right,
+            ),
+            Direction::new(
+                VerticalDirectionComponent::Equal,
+                HorizontalDirectionComponent::Left,
+            ),
+        ]
+    }
 }
 
 enum VerticalDirectionComponent {
@@ -105,75 +146,95 @@ impl SeatingPosition {
     }
 
     fn adjacent_positions(&self) -> Vec<Self> {
-        [
-            Direction::new(
-                VerticalDirectionComponent::Above,
-                HorizontalDirectionComponent::Equal,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Above,
-                HorizontalDirectionComponent::Right,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Above,
-                HorizontalDirectionComponent::Left,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Below,
-                HorizontalDirectionComponent::Equal,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Below,
-                HorizontalDirectionComponent::Right,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Below,
-                HorizontalDirectionComponent::Left,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Equal,
-                HorizontalDirectionComponent::Right,
-            ),
-            Direction::new(
-                VerticalDirectionComponent::Equal,
-                HorizontalDirectionComponent::Left,
-            ),
-        ]
-        .iter()
-        .map(|direction| self.get_relative_position(direction))
-        .filter(Option::is_some)
-        .map(|option_position: Option<Self>| option_position.unwrap())
-        .collect::<Vec<Self>>()
+        Direction::all()
+            .iter()
+            .filter_map(|direction| self.get_relative_position(direction))
+            .collect::<Vec<Self>>()
+    }
+}
+
+#[derive(Copy, Clone)]
+enum SeatingNeighbourhood {
+    Adjacent,
+    LineOfSight,
+}
+
+impl SeatingNeighbourhood {
+    fn occupied_seat_tolerance(&self) -> usize {
+        match self {
+            SeatingNeighbourhood::Adjacent => 4,
+            SeatingNeighbourhood::LineOfSight => 5,
+        }
     }
 }
 
 struct SeatingState {
     seating_positions: Vec<Vec<SeatingElement>>,
+    neighbourhood: SeatingNeighbourhood,
     reached_stability: bool,
 }
 
 impl SeatingState {
-    fn new(seating_positions: Vec<Vec<SeatingElement>>) -> Self {
+    fn new(
+        seating_positions: Vec<Vec<SeatingElement>>,
+        neighbourhood: SeatingNeighbourhood,
+    ) -> Self {
         SeatingState {
             seating_positions,
+            neighbourhood,
             reached_stability: false,
         }
     }
 
+    fn is_valid_position(&self, position: &SeatingPosition) -> bool {
+        position.row() < self.seating_positions.len()
+            && position.column() < self.seating_positions.first().unwrap().len()
+    }
+
     fn valid_adjacent_positions(&self, position: &SeatingPosition) -> Vec<SeatingPosition> {
         position
             .adjacent_positions()
             .iter()
-            .filter(|adjacent_position| {
-                adjacent_position.row() < self.seating_positions.len()
-                    && adjacent_position.column() < self.seating_positions.first().unwrap().len()
-            })
+            .filter(|adjacent_position| self.is_valid_position(adjacent_position))
             .copied()
             .collect::<Vec<SeatingPosition>>()
     }
 
-    fn count_adjacent_occupied_seats(&self, seating_position: &SeatingPosition) -> usize {
-        self.valid_adjacent_positions(seating_position)
+    fn first_visible_seat_position(
+        &self,
+        position: &SeatingPosition,
+        direction: &Direction,
+    ) -> Option<SeatingPosition> {
+        let mut visible_position = position.get_relative_position(direction)?;
+
+        while self.is_valid_position(&visible_position) {
+            if let Some(SeatingElement::Seat(_)) =
+                self.get_seating_element_at_position(&visible_position)
+            {
+                return Some(visible_position);
+            }
+            visible_position = visible_position.get_relative_position(direction)?;
+        }
+
+        None
+    }
+
+    fn visible_seat_positions(&self, position: &SeatingPosition) -> Vec<SeatingPosition> {
+        Direction::all()
+            .iter()
+            .filter_map(|direction| self.first_visible_seat_position(position, direction))
+            .collect::<Vec<SeatingPosition>>()
+    }
+
+    fn neighbouring_positions(&self, position: &SeatingPosition) -> Vec<SeatingPosition> {
+        match self.neighbourhood {
+            SeatingNeighbourhood::Adjacent => self.valid_adjacent_positions(position),
+            SeatingNeighbourhood::LineOfSight => self.visible_seat_positions(position),
+        }
+    }
+
+    fn count_neighbouring_occupied_seats(&self, seating_position: &SeatingPosition) -> usize {
+        self.neighbouring_positions(seating_position)
             .iter()
             .filter(|position| {
                 matches!(
@@ -203,14 +264,16 @@ impl SeatingState {
             .unwrap()
         {
             SeatingElement::Seat(SeatOccupancy::Occupied) => {
-                if self.count_adjacent_occupied_seats(&seating_position) >= 4 {
+                if self.count_neighbouring_occupied_seats(&seating_position)
+                    >= self.neighbourhood.occupied_seat_tolerance()
+                {
                     SeatingElement::Seat(SeatOccupancy::Empty)
                 } else {
                     SeatingElement::Seat(SeatOccupancy::Occupied)
                 }
             }
             SeatingElement::Seat(SeatOccupancy::Empty) => {
-                if self.count_adjacent_occupied_seats(&seating_position) == 0 {
+                if self.count_neighbouring_occupied_seats(&seating_position) == 0 {
                     SeatingElement::Seat(SeatOccupancy::Occupied)
                 } else {
                     SeatingElement::Seat(SeatOccupancy::Empty)
@@ -269,31 +332,92 @@ impl SeatingState {
     }
 }
 
-fn seating_state_from_element_row_strings(
-    seating_element_row_strings: Vec<String>,
-) -> anyhow::Result<SeatingState> {
-    let seating_positions = seating_element_row_strings
+fn seating_elements_from_row_strings(
+    seating_element_row_strings: &[String],
+) -> anyhow::Result<Vec<Vec<SeatingElement>>> {
+    let seating_elements = seating_element_row_strings
         .iter()
         .map(|s| {
             s.chars()
                 .map(|c| c.to_string().as_str().parse())
                 .collect::<anyhow::Result<Vec<SeatingElement>>>()
         })
-        .collect::<anyhow::Result<Vec<Vec<SeatingElement>>>>();
+        .collect::<anyhow::Result<Vec<Vec<SeatingElement>>>>()?;
+
+    if let Some(first_row) = seating_elements.first() {
+        if let Some((index, row)) = seating_elements
+            .iter()
+            .enumerate()
+            .find(|(_index, row)| row.len() != first_row.len())
+        {
+            return Err(anyhow::Error::msg(format!(
+                "Seating row {} has {} elements but row 1 has {}",
+                index + 1,
+                row.len(),
+                first_row.len()
+            )));
+        }
+    }
 
-    Ok(SeatingState::new(seating_positions?))
+    Ok(seating_elements)
+}
+
+fn count_occupied_seats_after_stabilisation_in_neighbourhood(
+    seating_elements: Vec<Vec<SeatingElement>>,
+    neighbourhood: SeatingNeighbourhood,
+) -> usize {
+    let mut seating_state = SeatingState::new(seating_elements, neighbourhood);
+
+    while !seating_state.is_stable() {
+        seating_state.apply_occupancy_rule();
+    }
+
+    seating_state.occupied_seats()
 }
 
 pub fn count_occupied_seats_after_occupancy_stabilisation(
     seating_element_row_strings: Vec<String>,
 ) -> anyhow::Result<usize> {
-    let mut seating_state = seating_state_from_element_row_strings(seating_element_row_strings)?;
+    Ok(count_occupied_seats_after_stabilisation_in_neighbourhood(
+        seating_elements_from_row_strings(&seating_element_row_strings)?,
+        SeatingNeighbourhood::Adjacent,
+    ))
+}
 
-    while !seating_state.is_stable() {
-        seating_state.apply_occupancy_rule();
+pub fn count_occupied_seats_after_line_of_sight_occupancy_stabilisation(
+    seating_element_row_strings: Vec<String>,
+) -> anyhow::Result<usize> {
+    Ok(count_occupied_seats_after_stabilisation_in_neighbourhood(
+        seating_elements_from_row_strings(&seating_element_row_strings)?,
+        SeatingNeighbourhood::LineOfSight,
+    ))
+}
+
+pub struct Day11Solver;
+
+impl Solver for Day11Solver {
+    type Input = Vec<Vec<SeatingElement>>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        seating_elements_from_row_strings(&input.lines())
     }
 
-    Ok(seating_state.occupied_seats())
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let neighbourhood = match part {
+            ChallengePart::One => SeatingNeighbourhood::Adjacent,
+            ChallengePart::Two => SeatingNeighbourhood::LineOfSight,
+        };
+
+        Ok(count_occupied_seats_after_stabilisation_in_neighbourhood(
+            input,
+            neighbourhood,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -304,7 +428,7 @@ mod tests {
 
     #[test]
     fn counts_occupied_seats_after_occupancy_stabilisation() {
-        let seating_element_row_strings: Vec<String> = vec![
+        let seating_element_row_strings: Vec<String> = [
             "L.LL.LL.LL",
             "LLLLLLL.LL",
             "L.L.L..L..",
@@ -326,4 +450,43 @@ mod tests {
         )
         .is_equal_to(37);
     }
+
+    #[test]
+    fn counts_occupied_seats_after_line_of_sight_occupancy_stabilisation() {
+        let seating_element_row_strings: Vec<String> = [
+            "L.LL.LL.LL",
+            "LLLLLLL.LL",
+            "L.L.L..L..",
+            "LLLL.LL.LL",
+            "L.LL.LL.LL",
+            "L.LLLLL.LL",
+            "..L.L.....",
+            "LLLLLLLLLL",
+            "L.LLLLLL.L",
+            "L.LLLLL.LL",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_that(
+            &count_occupied_seats_after_line_of_sight_occupancy_stabilisation(
+                seating_element_row_strings,
+            )
+            .unwrap(),
+        )
+        .is_equal_to(26);
+    }
+
+    #[test]
+    fn fails_to_parse_rows_of_different_lengths() {
+        let seating_element_row_strings: Vec<String> =
+            ["L.#", "L"].iter().map(ToString::to_string).collect();
+
+        assert_that(
+            &count_occupied_seats_after_occupancy_stabilisation(seating_element_row_strings)
+                .is_err(),
+        )
+        .is_true();
+    }
 }
@@ -4,6 +4,10 @@ use std::str::FromStr;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 enum NavigationInstructionKind {
     North,
     South,
@@ -34,7 +38,7 @@ impl FromStr for NavigationInstructionKind {
     }
 }
 
-struct NavigationInstruction {
+pub struct NavigationInstruction {
     kind: NavigationInstructionKind,
     value: u64,
 }
@@ -201,7 +205,7 @@ impl ShipNavigator {
     fn apply_navigation_instructions(
         &mut self,
         navigation_instructions: Vec<NavigationInstruction>,
-    ) {
+    ) -> anyhow::Result<()> {
         for navigation_instruction in &navigation_instructions {
             match navigation_instruction.kind() {
                 NavigationInstructionKind::North => self
@@ -222,11 +226,13 @@ impl ShipNavigator {
                 NavigationInstructionKind::Right => self
                     .orientation
                     .apply_rotation(Degrees(navigation_instruction.value())),
-                NavigationInstructionKind::Forward => self
-                    .move_forward(navigation_instruction.value() as i64)
-                    .unwrap(),
+                NavigationInstructionKind::Forward => {
+                    self.move_forward(navigation_instruction.value() as i64)?
+                }
             }
         }
+
+        Ok(())
     }
 
     fn relative_position(&self) -> &RelativePosition {
@@ -283,7 +289,7 @@ impl ShipWaypointNavigator {
     fn apply_navigation_instructions(
         &mut self,
         navigation_instructions: Vec<NavigationInstruction>,
-    ) {
+    ) -> anyhow::Result<()> {
         for navigation_instruction in &navigation_instructions {
             match navigation_instruction.kind() {
                 NavigationInstructionKind::North => self
@@ -298,19 +304,19 @@ impl ShipWaypointNavigator {
                 NavigationInstructionKind::West => self
                     .waypoint_position_relative_to_ship
                     .translate_east(-(navigation_instruction.value() as i64)),
-                NavigationInstructionKind::Left => self
-                    .rotate_waypoint_about_ship(Degrees::from(
-                        -(navigation_instruction.value() as i64),
-                    ))
-                    .unwrap(),
-                NavigationInstructionKind::Right => self
-                    .rotate_waypoint_about_ship(Degrees(navigation_instruction.value()))
-                    .unwrap(),
+                NavigationInstructionKind::Left => self.rotate_waypoint_about_ship(
+                    Degrees::from(-(navigation_instruction.value() as i64)),
+                )?,
+                NavigationInstructionKind::Right => {
+                    self.rotate_waypoint_about_ship(Degrees(navigation_instruction.value()))?
+                }
                 NavigationInstructionKind::Forward => {
                     self.move_ship_to_waypoint_times(navigation_instruction.value());
                 }
             }
         }
+
+        Ok(())
     }
 
     fn ship_position(&self) -> &RelativePosition {
@@ -331,30 +337,66 @@ fn navigation_instructions_from_strings(
         .collect::<anyhow::Result<Vec<NavigationInstruction>>>()
 }
 
-pub fn get_manhattan_distance_to_directed_location(
-    navigation_instruction_strings: Vec<String>,
+fn manhattan_distance_to_directed_location(
+    navigation_instructions: Vec<NavigationInstruction>,
 ) -> anyhow::Result<u64> {
     let mut navigator =
         ShipNavigator::new(RelativePosition::default(), Orientation::new(90.into()));
 
-    navigator.apply_navigation_instructions(navigation_instructions_from_strings(
-        navigation_instruction_strings,
-    )?);
+    navigator.apply_navigation_instructions(navigation_instructions)?;
     Ok(manhattan_distance(navigator.relative_position()))
 }
 
-pub fn get_manhattan_distance_to_directed_location_with_waypoint_navigation(
-    navigation_instruction_strings: Vec<String>,
+fn manhattan_distance_to_directed_location_with_waypoint_navigation(
+    navigation_instructions: Vec<NavigationInstruction>,
 ) -> anyhow::Result<u64> {
     let mut navigator =
         ShipWaypointNavigator::new(RelativePosition::default(), RelativePosition::new(10, 1));
 
-    navigator.apply_navigation_instructions(navigation_instructions_from_strings(
-        navigation_instruction_strings,
-    )?);
+    navigator.apply_navigation_instructions(navigation_instructions)?;
     Ok(manhattan_distance(navigator.ship_position()))
 }
 
+pub fn get_manhattan_distance_to_directed_location(
+    navigation_instruction_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    manhattan_distance_to_directed_location(navigation_instructions_from_strings(
+        navigation_instruction_strings,
+    )?)
+}
+
+pub fn get_manhattan_distance_to_directed_location_with_waypoint_navigation(
+    navigation_instruction_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    manhattan_distance_to_directed_location_with_waypoint_navigation(
+        navigation_instructions_from_strings(navigation_instruction_strings)?,
+    )
+}
+
+pub struct Day12Solver;
+
+impl Solver for Day12Solver {
+    type Input = Vec<NavigationInstruction>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        navigation_instructions_from_strings(input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => manhattan_distance_to_directed_location(input),
+            ChallengePart::Two => {
+                manhattan_distance_to_directed_location_with_waypoint_navigation(input)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -363,7 +405,7 @@ mod tests {
 
     #[test]
     fn gets_manhattan_distance_to_directed_location() {
-        let navigation_instruction_strings = vec!["F10", "N3", "F7", "R90", "F11"]
+        let navigation_instruction_strings = ["F10", "N3", "F7", "R90", "F11"]
             .iter()
             .map(ToString::to_string)
             .collect();
@@ -376,7 +418,7 @@ mod tests {
 
     #[test]
     fn gets_manhattan_distance_to_directed_location_with_waypoint_navigation() {
-        let navigation_instruction_strings = vec!["F10", "N3", "F7", "R90", "F11"]
+        let navigation_instruction_strings = ["F10", "N3", "F7", "R90", "F11"]
             .iter()
             .map(ToString::to_string)
             .collect();
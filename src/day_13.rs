@@ -1,18 +1,30 @@
-use std::num::ParseIntError;
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
 
 #[derive(Copy, Clone)]
-struct Bus {
+pub struct Bus {
     id: u64,
+    offset: u64,
 }
 
 impl Bus {
-    fn new(id: u64) -> Self {
-        Bus { id }
+    fn new(id: u64, offset: u64) -> Self {
+        Bus { id, offset }
     }
 
     fn id(&self) -> u64 {
         self.id
     }
+
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    fn departs_at_offset_from(&self, timestamp: u64) -> bool {
+        (timestamp % self.id + self.offset % self.id).is_multiple_of(self.id)
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -66,41 +78,141 @@ impl BusScheduler {
 
         self.earliest_departure(potential_departures)
     }
+
+    fn get_earliest_timestamp_matching_offsets(&self) -> anyhow::Result<u64> {
+        let overflow_error =
+            || anyhow::Error::msg("Timestamp exceeded the maximum supported value");
+
+        let mut timestamp: u64 = 0;
+        let mut period: u64 = 1;
+
+        for bus in &self.buses {
+            let mut steps = 0;
+            while !bus.departs_at_offset_from(timestamp) {
+                if steps == bus.id() {
+                    return Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+                        format!(
+                            "No timestamp matches offset {} of bus {}",
+                            bus.offset(),
+                            bus.id()
+                        ),
+                    )));
+                }
+                timestamp = timestamp.checked_add(period).ok_or_else(overflow_error)?;
+                steps += 1;
+            }
+            period = lowest_common_multiple(period, bus.id()).ok_or_else(overflow_error)?;
+        }
+
+        Ok(timestamp)
+    }
+}
+
+fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
 }
 
-fn bus_ids_from_string(string: &str) -> Result<Vec<u64>, ParseIntError> {
-    string
+fn lowest_common_multiple(a: u64, b: u64) -> Option<u64> {
+    (a / greatest_common_divisor(a, b)).checked_mul(b)
+}
+
+fn buses_from_string(string: &str) -> anyhow::Result<Vec<Bus>> {
+    let buses = string
         .split(',')
-        .into_iter()
-        .filter(|split| split != &"x")
-        .map(|split| split.parse())
-        .collect()
+        .enumerate()
+        .filter(|(_offset, split)| split != &"x")
+        .map(|(offset, split)| -> anyhow::Result<Bus> {
+            let id: u64 = split.parse()?;
+            if id == 0 {
+                return Err(anyhow::Error::msg("Bus id must be greater than zero"));
+            }
+            Ok(Bus::new(id, offset as u64))
+        })
+        .collect::<anyhow::Result<Vec<Bus>>>()?;
+
+    if buses.is_empty() {
+        return Err(anyhow::Error::msg("Could not find any bus ids"));
+    }
+
+    Ok(buses)
 }
 
 fn time_and_buses_from_input_lines(input_strings: Vec<String>) -> anyhow::Result<(u64, Vec<Bus>)> {
     if input_strings.len() != 2 {
         Err(anyhow::Error::msg("Invalid string input"))
     } else {
-        let earliest_departure_time: u64 = input_strings.get(0).unwrap().parse()?;
-        let buses = bus_ids_from_string(input_strings.get(1).unwrap())?
-            .iter()
-            .copied()
-            .map(Bus::new)
-            .collect();
+        let earliest_departure_time: u64 = input_strings.first().unwrap().parse()?;
+        let buses = buses_from_string(input_strings.get(1).unwrap())?;
 
         Ok((earliest_departure_time, buses))
     }
 }
 
+fn product_of_id_of_earliest_bus_and_wait_time(
+    earliest_departure_time: u64,
+    buses: Vec<Bus>,
+) -> u64 {
+    let bus_scheduler = BusScheduler::new(buses);
+    let earliest_departure =
+        bus_scheduler.get_bus_earliest_possible_departure(earliest_departure_time);
+
+    earliest_departure.bus().id() * (earliest_departure.time() - earliest_departure_time)
+}
+
+fn earliest_timestamp_matching_bus_offsets(buses: Vec<Bus>) -> anyhow::Result<u64> {
+    let bus_scheduler = BusScheduler::new(buses);
+
+    bus_scheduler.get_earliest_timestamp_matching_offsets()
+}
+
 pub fn get_product_of_id_of_earliest_bus_and_wait_time(
     input_strings: Vec<String>,
 ) -> anyhow::Result<u64> {
     let (earliest_departure_time, buses) = time_and_buses_from_input_lines(input_strings)?;
-    let bus_scheduler = BusScheduler::new(buses);
-    let earliest_departure =
-        bus_scheduler.get_bus_earliest_possible_departure(earliest_departure_time);
 
-    Ok(earliest_departure.bus().id() * (earliest_departure.time() - earliest_departure_time))
+    Ok(product_of_id_of_earliest_bus_and_wait_time(
+        earliest_departure_time,
+        buses,
+    ))
+}
+
+pub fn get_earliest_timestamp_matching_bus_offsets(
+    input_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    let (_earliest_departure_time, buses) = time_and_buses_from_input_lines(input_strings)?;
+
+    earliest_timestamp_matching_bus_offsets(buses)
+}
+
+pub struct Day13Solver;
+
+impl Solver for Day13Solver {
+    type Input = (u64, Vec<Bus>);
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        time_and_buses_from_input_lines(input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let (earliest_departure_time, buses) = input;
+
+        match part {
+            ChallengePart::One => Ok(product_of_id_of_earliest_bus_and_wait_time(
+                earliest_departure_time,
+                buses,
+            )),
+            ChallengePart::Two => earliest_timestamp_matching_bus_offsets(buses),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -111,7 +223,7 @@ mod tests {
 
     #[test]
     fn gets_product_of_id_of_earliest_bus_and_bus_stop_wait_time() {
-        let input = vec!["939", "7,13,x,x,59,x,31,19"]
+        let input = ["939", "7,13,x,x,59,x,31,19"]
             .iter()
             .map(ToString::to_string)
             .collect::<Vec<String>>();
@@ -119,4 +231,36 @@ mod tests {
         assert_that(&get_product_of_id_of_earliest_bus_and_wait_time(input).unwrap())
             .is_equal_to(295);
     }
+
+    #[test]
+    fn gets_earliest_timestamp_matching_bus_offsets() {
+        let input = ["939", "7,13,x,x,59,x,31,19"]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+
+        assert_that(&get_earliest_timestamp_matching_bus_offsets(input).unwrap())
+            .is_equal_to(1068781);
+    }
+
+    #[test]
+    fn gets_earliest_timestamp_matching_offsets_of_large_bus_ids() {
+        let input = ["0", "1789,37,47,1889"]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+
+        assert_that(&get_earliest_timestamp_matching_bus_offsets(input).unwrap())
+            .is_equal_to(1202161486);
+    }
+
+    #[test]
+    fn fails_to_match_bus_offsets_that_never_align() {
+        let input = ["0", "4,x,x,2"]
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+
+        assert_that(&get_earliest_timestamp_matching_bus_offsets(input)).is_err();
+    }
 }
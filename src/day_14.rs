@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+const BITMASK_LENGTH: usize = 36;
+const MAX_FLOATING_BITS: u32 = 16;
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(Copy, Clone, Default)]
+pub struct Bitmask {
+    ones: u64,
+    zeros: u64,
+    floating: u64,
+}
+
+impl Bitmask {
+    fn apply_to_value(&self, value: u64) -> u64 {
+        (value | self.ones) & !self.zeros
+    }
+
+    fn decode_addresses(&self, address: u64) -> anyhow::Result<Vec<u64>> {
+        if self.floating.count_ones() > MAX_FLOATING_BITS {
+            return Err(anyhow::Error::msg(format!(
+                "Mask has {} floating bits, more than the {} supported when decoding addresses",
+                self.floating.count_ones(),
+                MAX_FLOATING_BITS
+            )));
+        }
+
+        let base_address = (address | self.ones) & !self.floating;
+
+        let floating_bits: Vec<u64> = (0..BITMASK_LENGTH)
+            .map(|bit| 1 << bit)
+            .filter(|bit| self.floating & bit != 0)
+            .collect();
+
+        Ok((0..(1_u64 << floating_bits.len()))
+            .map(|combination| {
+                floating_bits
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _bit)| combination & (1 << index) != 0)
+                    .fold(base_address, |address, (_index, bit)| address | bit)
+            })
+            .collect())
+    }
+}
+
+impl FromStr for Bitmask {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != BITMASK_LENGTH {
+            return Err(anyhow::Error::msg(format!(
+                "Bitmask '{}' must be {} characters long",
+                s, BITMASK_LENGTH
+            )));
+        }
+
+        s.chars()
+            .rev()
+            .enumerate()
+            .try_fold(Bitmask::default(), |mut bitmask, (bit, c)| {
+                match c {
+                    '1' => bitmask.ones |= 1 << bit,
+                    '0' => bitmask.zeros |= 1 << bit,
+                    'X' => bitmask.floating |= 1 << bit,
+                    _ => {
+                        return Err(anyhow::Error::msg(format!(
+                            "Could not parse bitmask character '{}'",
+                            c
+                        )))
+                    }
+                }
+                Ok(bitmask)
+            })
+    }
+}
+
+#[cfg_attr(test, derive(Debug))]
+pub enum DockingInstruction {
+    SetMask(Bitmask),
+    WriteMemory { address: u64, value: u64 },
+}
+
+impl FromStr for DockingInstruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref MASK_REGEX: Regex = Regex::new(r"^mask = (?P<mask>[01X]+)$").unwrap();
+            static ref MEMORY_REGEX: Regex =
+                Regex::new(r"^mem\[(?P<address>\d+)\] = (?P<value>\d+)$").unwrap();
+        }
+
+        if let Some(captures) = MASK_REGEX.captures(s) {
+            return Ok(DockingInstruction::SetMask(
+                captures.name("mask").unwrap().as_str().parse()?,
+            ));
+        }
+
+        match MEMORY_REGEX.captures(s) {
+            Some(captures) => Ok(DockingInstruction::WriteMemory {
+                address: captures.name("address").unwrap().as_str().parse()?,
+                value: captures.name("value").unwrap().as_str().parse()?,
+            }),
+            None => Err(anyhow::Error::msg(format!(
+                "Could not parse docking instruction '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum DecoderVersion {
+    ValueMasking,
+    FloatingAddress,
+}
+
+struct DockingProgram {
+    decoder_version: DecoderVersion,
+    mask: Bitmask,
+    memory: HashMap<u64, u64>,
+}
+
+impl DockingProgram {
+    fn new(decoder_version: DecoderVersion) -> Self {
+        DockingProgram {
+            decoder_version,
+            mask: Bitmask::default(),
+            memory: HashMap::new(),
+        }
+    }
+
+    fn execute(&mut self, instruction: &DockingInstruction) -> anyhow::Result<()> {
+        match instruction {
+            DockingInstruction::SetMask(mask) => self.mask = *mask,
+            DockingInstruction::WriteMemory { address, value } => match self.decoder_version {
+                DecoderVersion::ValueMasking => {
+                    self.memory
+                        .insert(*address, self.mask.apply_to_value(*value));
+                }
+                DecoderVersion::FloatingAddress => {
+                    self.mask
+                        .decode_addresses(*address)?
+                        .into_iter()
+                        .for_each(|decoded_address| {
+                            self.memory.insert(decoded_address, *value);
+                        });
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn sum_of_memory_values(&self) -> u64 {
+        self.memory.values().sum()
+    }
+}
+
+fn docking_instructions_from_strings(
+    instruction_strings: &[String],
+) -> anyhow::Result<Vec<DockingInstruction>> {
+    instruction_strings.iter().map(|s| s.parse()).collect()
+}
+
+fn sum_of_memory_values_after_docking_program_with_decoder(
+    instructions: Vec<DockingInstruction>,
+    decoder_version: DecoderVersion,
+) -> anyhow::Result<u64> {
+    let mut docking_program = DockingProgram::new(decoder_version);
+    instructions
+        .iter()
+        .try_for_each(|instruction| docking_program.execute(instruction))?;
+
+    Ok(docking_program.sum_of_memory_values())
+}
+
+pub fn sum_of_memory_values_after_docking_program(
+    instruction_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    sum_of_memory_values_after_docking_program_with_decoder(
+        docking_instructions_from_strings(&instruction_strings)?,
+        DecoderVersion::ValueMasking,
+    )
+}
+
+pub fn sum_of_memory_values_after_floating_address_docking_program(
+    instruction_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    sum_of_memory_values_after_docking_program_with_decoder(
+        docking_instructions_from_strings(&instruction_strings)?,
+        DecoderVersion::FloatingAddress,
+    )
+}
+
+pub struct Day14Solver;
+
+impl Solver for Day14Solver {
+    type Input = Vec<DockingInstruction>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        docking_instructions_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let decoder_version = match part {
+            ChallengePart::One => DecoderVersion::ValueMasking,
+            ChallengePart::Two => DecoderVersion::FloatingAddress,
+        };
+
+        sum_of_memory_values_after_docking_program_with_decoder(input, decoder_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn sums_memory_values_after_docking_program() {
+        let instruction_strings = [
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+            "mem[8] = 11",
+            "mem[7] = 101",
+            "mem[8] = 0",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_that(&sum_of_memory_values_after_docking_program(instruction_strings).unwrap())
+            .is_equal_to(165);
+    }
+
+    #[test]
+    fn sums_memory_values_after_floating_address_docking_program() {
+        let instruction_strings = [
+            "mask = 000000000000000000000000000000X1001X",
+            "mem[42] = 100",
+            "mask = 00000000000000000000000000000000X0XX",
+            "mem[26] = 1",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_that(
+            &sum_of_memory_values_after_floating_address_docking_program(instruction_strings)
+                .unwrap(),
+        )
+        .is_equal_to(208);
+    }
+
+    #[test]
+    fn fails_to_decode_addresses_with_too_many_floating_bits() {
+        let instruction_strings = ["mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X", "mem[8] = 11"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_that(
+            &sum_of_memory_values_after_floating_address_docking_program(instruction_strings)
+                .is_err(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_instruction() {
+        assert_that(&"mem[8] 11".parse::<DockingInstruction>()).is_err();
+    }
+}
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 use std::num::ParseIntError;
 
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
 struct RecitationGame {
     last_spoken_number: Option<u64>,
     spoken_numbers: HashMap<u64, u64>,
@@ -24,16 +28,13 @@ impl RecitationGame {
 
     fn turns_since_n_was_spoken(&self, n: u64) -> Option<u64> {
         let last_turn = self.current_turn - 1;
-        match self.spoken_numbers.get(&n) {
-            Some(turn) => Some(last_turn - turn),
-            None => None,
-        }
+        self.spoken_numbers.get(&n).map(|turn| last_turn - turn)
     }
 
     fn memorise_last_spoken_number(&mut self) {
-        if self.last_spoken_number.is_some() {
+        if let Some(last_spoken_number) = self.last_spoken_number {
             self.spoken_numbers
-                .insert(self.last_spoken_number.unwrap(), self.current_turn - 1);
+                .insert(last_spoken_number, self.current_turn - 1);
         }
     }
 
@@ -59,11 +60,7 @@ impl RecitationGame {
     }
 }
 
-pub fn nth_spoken_number_in_recitation_game(
-    n: u64,
-    starting_numbers_string: String,
-) -> anyhow::Result<u64> {
-    let starting_numbers = starting_numbers_from_string(starting_numbers_string)?;
+fn nth_spoken_number(n: u64, starting_numbers: Vec<u64>) -> anyhow::Result<u64> {
     let mut recitation_game = RecitationGame::new(starting_numbers);
 
     let mut loop_count = 0;
@@ -72,17 +69,50 @@ pub fn nth_spoken_number_in_recitation_game(
         loop_count += 1;
     }
 
-    Ok(recitation_game
+    recitation_game
         .last_spoken_number()
-        .ok_or_else(|| anyhow::Error::msg("No words spoken"))?)
+        .ok_or_else(|| anyhow::Error::msg("No words spoken"))
 }
 
-fn starting_numbers_from_string(
+pub fn nth_spoken_number_in_recitation_game(
+    n: u64,
     starting_numbers_string: String,
-) -> Result<Vec<u64>, ParseIntError> {
+) -> anyhow::Result<u64> {
+    nth_spoken_number(n, starting_numbers_from_string(&starting_numbers_string)?)
+}
+
+fn starting_numbers_from_string(starting_numbers_string: &str) -> Result<Vec<u64>, ParseIntError> {
     starting_numbers_string.split(',').map(str::parse).collect()
 }
 
+pub struct Day15Solver;
+
+impl Solver for Day15Solver {
+    type Input = Vec<u64>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        let starting_numbers_string = input
+            .lines()
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::Error::msg("Input is empty"))?;
+
+        Ok(starting_numbers_from_string(&starting_numbers_string)?)
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => nth_spoken_number(2020, input),
+            ChallengePart::Two => nth_spoken_number(30000000, input),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spectral::prelude::*;
@@ -5,7 +5,11 @@ use std::str::FromStr;
 
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::hash::Hash;
+
+use crate::challenge::ChallengePart;
+use crate::elimination::resolve_by_elimination;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
 
 struct NumberRange {
     lower: u64,
@@ -27,7 +31,7 @@ impl NumberRange {
 }
 
 #[derive(Clone)]
-struct UnidentifiedTicketFieldValues {
+pub struct UnidentifiedTicketFieldValues {
     field_values: Vec<u64>,
 }
 
@@ -59,7 +63,7 @@ impl IdentifiedTicketFieldValues {
     }
 }
 
-struct TicketFieldRule {
+pub struct TicketFieldRule {
     name: String,
     number_ranges: Vec<NumberRange>,
 }
@@ -99,7 +103,7 @@ impl FromStr for TicketFieldRule {
                     .collect::<Result<Vec<NumberRange>, ParseIntError>>()?;
 
                 Ok(TicketFieldRule {
-                    name: name,
+                    name,
                     number_ranges,
                 })
             }
@@ -152,8 +156,8 @@ impl TicketValidator {
     ) -> Vec<UnidentifiedTicketFieldValues> {
         ticket_values
             .iter()
+            .filter(|&ticket_value| self.valid(ticket_value))
             .cloned()
-            .filter(|ticket_value| self.valid(ticket_value))
             .collect()
     }
 
@@ -172,12 +176,10 @@ impl TicketValidator {
     fn identify_ticket_field_indexes(
         &self,
         ticket_values: Vec<UnidentifiedTicketFieldValues>,
-    ) -> HashMap<usize, String> {
+    ) -> anyhow::Result<HashMap<usize, String>> {
         let mut possible_field_names: HashMap<usize, HashSet<String>> =
             self.new_possible_field_names_map();
-        let mut identified_field_indexes: HashMap<usize, String> = HashMap::new();
         let valid_tickets = self.valid_tickets(ticket_values);
-        let indexes: Vec<usize> = possible_field_names.keys().cloned().collect();
 
         valid_tickets.iter().for_each(|ticket| {
             ticket
@@ -196,31 +198,15 @@ impl TicketValidator {
                 });
         });
 
-        while identified_field_indexes.keys().len() < indexes.len() {
-            for field_index in &indexes {
-                if possible_field_names.get(&field_index).unwrap().len() == 1 {
-                    let field_name =
-                        only_element_in_set(possible_field_names.get(&field_index).unwrap());
-                    identified_field_indexes.insert(*field_index, field_name.clone());
-
-                    possible_field_names
-                        .values_mut()
-                        .for_each(|possible_index_field_names| {
-                            possible_index_field_names.remove(field_name.as_str());
-                        });
-                }
-            }
-        }
-
-        identified_field_indexes
+        resolve_by_elimination(possible_field_names)
     }
 
     fn identify_ticket_values_from_nearby_tickets(
         &self,
         ticket: UnidentifiedTicketFieldValues,
         nearby_ticket_values: Vec<UnidentifiedTicketFieldValues>,
-    ) -> IdentifiedTicketFieldValues {
-        let identified_ticket_indexes = self.identify_ticket_field_indexes(nearby_ticket_values);
+    ) -> anyhow::Result<IdentifiedTicketFieldValues> {
+        let identified_ticket_indexes = self.identify_ticket_field_indexes(nearby_ticket_values)?;
         let identified_ticket_map: HashMap<String, u64> = identified_ticket_indexes
             .keys()
             .map(|key| {
@@ -231,15 +217,10 @@ impl TicketValidator {
             })
             .collect();
 
-        IdentifiedTicketFieldValues::new(identified_ticket_map)
+        Ok(IdentifiedTicketFieldValues::new(identified_ticket_map))
     }
 }
 
-fn only_element_in_set<T: Hash + Default + Clone>(set: &HashSet<T>) -> T {
-    set.iter()
-        .fold(T::default(), |_acc, value| value.to_owned())
-}
-
 fn number_range_string_to_range(s: &str) -> Result<NumberRange, ParseIntError> {
     let numbers: Vec<u64> = s
         .split('-')
@@ -247,7 +228,7 @@ fn number_range_string_to_range(s: &str) -> Result<NumberRange, ParseIntError> {
         .collect::<Result<Vec<u64>, ParseIntError>>()?;
 
     Ok(NumberRange::new(
-        *numbers.get(0).unwrap(),
+        *numbers.first().unwrap(),
         *numbers.get(1).unwrap(),
     ))
 }
@@ -264,9 +245,11 @@ fn ticket_field_rules_from_input_lines(
 fn my_ticket_from_input_lines(
     input_lines: &[String],
 ) -> anyhow::Result<UnidentifiedTicketFieldValues> {
-    Ok(ticket_from_field_values_string(
-        input_lines.get(1).unwrap(),
-    )?)
+    let field_values_string = input_lines
+        .get(1)
+        .ok_or_else(|| anyhow::Error::msg("Could not find field values of your ticket"))?;
+
+    Ok(ticket_from_field_values_string(field_values_string)?)
 }
 
 fn ticket_from_field_values_string<S: AsRef<str>>(
@@ -283,59 +266,64 @@ fn ticket_from_field_values_string<S: AsRef<str>>(
 fn nearby_tickets_from_input_lines(
     input_lines: &[String],
 ) -> Result<Vec<UnidentifiedTicketFieldValues>, ParseIntError> {
-    input_lines[1..]
+    input_lines
         .iter()
+        .skip(1)
         .map(ticket_from_field_values_string)
         .collect()
 }
 
-fn parse_input_lines(
-    input_lines: Vec<String>,
+fn record_lines(record: &str) -> Vec<String> {
+    record.lines().map(ToString::to_string).collect()
+}
+
+fn parse_input_records(
+    input_records: Vec<String>,
 ) -> anyhow::Result<(
     Vec<TicketFieldRule>,
     UnidentifiedTicketFieldValues,
     Vec<UnidentifiedTicketFieldValues>,
 )> {
-    let empty_line_indexes: Vec<usize> = input_lines
-        .iter()
-        .enumerate()
-        .filter(|(_index, line)| line.is_empty())
-        .map(|(index, _line)| index)
-        .collect();
-
-    let empty_line_index_1 = *empty_line_indexes.get(0).unwrap();
-    let empty_line_index_2 = *empty_line_indexes.get(1).unwrap();
+    let (rules_record, my_ticket_record, nearby_tickets_record) = match input_records.as_slice() {
+        [rules_record, my_ticket_record, nearby_tickets_record] => {
+            (rules_record, my_ticket_record, nearby_tickets_record)
+        }
+        _ => {
+            return Err(anyhow::Error::msg(format!(
+                "Expected 3 input sections but found {}",
+                input_records.len()
+            )))
+        }
+    };
 
-    let ticket_field_rules =
-        ticket_field_rules_from_input_lines(&input_lines[0..empty_line_index_1])?;
-    let my_ticket =
-        my_ticket_from_input_lines(&input_lines[(empty_line_index_1 + 1)..empty_line_index_2])?;
-    let nearby_tickets = nearby_tickets_from_input_lines(&input_lines[(empty_line_index_2 + 1)..])?;
+    let ticket_field_rules = ticket_field_rules_from_input_lines(&record_lines(rules_record))?;
+    let my_ticket = my_ticket_from_input_lines(&record_lines(my_ticket_record))?;
+    let nearby_tickets = nearby_tickets_from_input_lines(&record_lines(nearby_tickets_record))?;
 
     Ok((ticket_field_rules, my_ticket, nearby_tickets))
 }
 
-pub fn ticket_scanning_error_rate_for_input_nearby_tickets(
-    input_lines: Vec<String>,
-) -> anyhow::Result<u64> {
-    let (ticket_field_rules, _my_ticket, nearby_tickets) = parse_input_lines(input_lines)?;
+fn ticket_scanning_error_rate(
+    ticket_field_rules: Vec<TicketFieldRule>,
+    nearby_tickets: Vec<UnidentifiedTicketFieldValues>,
+) -> u64 {
     let ticket_validator = TicketValidator::new(ticket_field_rules);
 
-    let error_rate: u64 = nearby_tickets
+    nearby_tickets
         .iter()
-        .map(|ticket| ticket_validator.get_invalid_field_values(ticket))
-        .flatten()
-        .sum();
-
-    Ok(error_rate)
+        .flat_map(|ticket| ticket_validator.get_invalid_field_values(ticket))
+        .sum()
 }
 
-pub fn product_of_my_departure_field_values(input_lines: Vec<String>) -> anyhow::Result<u64> {
-    let (ticket_field_rules, my_ticket, nearby_tickets) = parse_input_lines(input_lines)?;
+fn product_of_departure_field_values(
+    ticket_field_rules: Vec<TicketFieldRule>,
+    my_ticket: UnidentifiedTicketFieldValues,
+    nearby_tickets: Vec<UnidentifiedTicketFieldValues>,
+) -> anyhow::Result<u64> {
     let ticket_validator = TicketValidator::new(ticket_field_rules);
 
     let identified_ticket_values =
-        ticket_validator.identify_ticket_values_from_nearby_tickets(my_ticket, nearby_tickets);
+        ticket_validator.identify_ticket_values_from_nearby_tickets(my_ticket, nearby_tickets)?;
 
     Ok(identified_ticket_values
         .values_of_fields_starting_with("departure")
@@ -343,6 +331,56 @@ pub fn product_of_my_departure_field_values(input_lines: Vec<String>) -> anyhow:
         .product())
 }
 
+pub fn ticket_scanning_error_rate_for_input_nearby_tickets(
+    input_records: Vec<String>,
+) -> anyhow::Result<u64> {
+    let (ticket_field_rules, _my_ticket, nearby_tickets) = parse_input_records(input_records)?;
+
+    Ok(ticket_scanning_error_rate(
+        ticket_field_rules,
+        nearby_tickets,
+    ))
+}
+
+pub fn product_of_my_departure_field_values(input_records: Vec<String>) -> anyhow::Result<u64> {
+    let (ticket_field_rules, my_ticket, nearby_tickets) = parse_input_records(input_records)?;
+
+    product_of_departure_field_values(ticket_field_rules, my_ticket, nearby_tickets)
+}
+
+pub struct Day16Solver;
+
+impl Solver for Day16Solver {
+    type Input = (
+        Vec<TicketFieldRule>,
+        UnidentifiedTicketFieldValues,
+        Vec<UnidentifiedTicketFieldValues>,
+    );
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        parse_input_records(input.records())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let (ticket_field_rules, my_ticket, nearby_tickets) = input;
+
+        match part {
+            ChallengePart::One => Ok(ticket_scanning_error_rate(
+                ticket_field_rules,
+                nearby_tickets,
+            )),
+            ChallengePart::Two => {
+                product_of_departure_field_values(ticket_field_rules, my_ticket, nearby_tickets)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter::FromIterator;
@@ -351,9 +389,13 @@ mod tests {
 
     use super::*;
 
+    fn records_from_lines(lines: &[&str]) -> Vec<String> {
+        ChallengeInput::new(&lines.join("\n")).records()
+    }
+
     #[test]
     fn gets_ticket_scanning_error_rate_for_input() {
-        let input_lines = vec![
+        let input_records = records_from_lines(&[
             "class: 1-3 or 5-7",
             "row: 6-11 or 33-44",
             "seat: 13-40 or 45-50",
@@ -366,18 +408,15 @@ mod tests {
             "40,4,50",
             "55,2,20",
             "38,6,12",
-        ]
-        .iter()
-        .map(ToString::to_string)
-        .collect();
+        ]);
 
-        assert_that(&ticket_scanning_error_rate_for_input_nearby_tickets(input_lines).unwrap())
+        assert_that(&ticket_scanning_error_rate_for_input_nearby_tickets(input_records).unwrap())
             .is_equal_to(71);
     }
 
     #[test]
     fn identifies_fields_in_my_ticket() {
-        let input_lines = vec![
+        let input_records = records_from_lines(&[
             "class: 0-1 or 4-19",
             "row: 0-5 or 8-19",
             "seat: 0-13 or 16-19",
@@ -389,20 +428,43 @@ mod tests {
             "3,9,18",
             "15,1,5",
             "5,14,9",
-        ]
-        .iter()
-        .map(ToString::to_string)
-        .collect();
+        ]);
         let (ticket_field_rules, _my_ticket, nearby_tickets) =
-            parse_input_lines(input_lines).unwrap();
+            parse_input_records(input_records).unwrap();
         let ticket_validator = TicketValidator::new(ticket_field_rules);
 
-        assert_that(&ticket_validator.identify_ticket_field_indexes(nearby_tickets)).is_equal_to(
-            HashMap::from_iter(vec![
-                (0, "row".to_string()),
-                (1, "class".to_string()),
-                (2, "seat".to_string()),
-            ]),
-        );
+        assert_that(
+            &ticket_validator
+                .identify_ticket_field_indexes(nearby_tickets)
+                .unwrap(),
+        )
+        .is_equal_to(HashMap::from_iter(vec![
+            (0, "row".to_string()),
+            (1, "class".to_string()),
+            (2, "seat".to_string()),
+        ]));
+    }
+
+    #[test]
+    fn fails_to_parse_input_with_extra_section() {
+        let input_records = records_from_lines(&[
+            "class: 1-3 or 5-7",
+            "",
+            "your ticket:",
+            "7",
+            "",
+            "nearby tickets:",
+            "3",
+            "",
+            "other tickets:",
+            "5",
+        ]);
+
+        assert_that(
+            &parse_input_records(input_records)
+                .err()
+                .map(|error| error.to_string()),
+        )
+        .is_equal_to(Some("Expected 3 input sections but found 4".to_string()));
     }
 }
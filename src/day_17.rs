@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CubeCoordinate(Vec<i64>);
+
+impl CubeCoordinate {
+    fn new(components: Vec<i64>) -> Self {
+        CubeCoordinate(components)
+    }
+
+    fn neighbours(&self) -> Vec<Self> {
+        let offsets = self.0.iter().fold(vec![vec![]], |offsets, _component| {
+            offsets
+                .iter()
+                .flat_map(|offset: &Vec<i64>| {
+                    (-1..=1).map(move |delta| {
+                        let mut offset = offset.clone();
+                        offset.push(delta);
+                        offset
+                    })
+                })
+                .collect::<Vec<Vec<i64>>>()
+        });
+
+        offsets
+            .iter()
+            .filter(|offset| offset.iter().any(|delta| *delta != 0))
+            .map(|offset| {
+                CubeCoordinate::new(
+                    self.0
+                        .iter()
+                        .zip(offset.iter())
+                        .map(|(component, delta)| component + delta)
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+struct PocketDimension {
+    active_cubes: HashSet<CubeCoordinate>,
+}
+
+impl PocketDimension {
+    fn new(active_cubes: HashSet<CubeCoordinate>) -> Self {
+        PocketDimension { active_cubes }
+    }
+
+    fn count_active_neighbours(&self) -> HashMap<CubeCoordinate, usize> {
+        let mut active_neighbour_counts: HashMap<CubeCoordinate, usize> = HashMap::new();
+
+        self.active_cubes.iter().for_each(|cube| {
+            cube.neighbours().into_iter().for_each(|neighbour| {
+                *active_neighbour_counts.entry(neighbour).or_insert(0) += 1;
+            })
+        });
+
+        active_neighbour_counts
+    }
+
+    fn run_cycle(&mut self) {
+        self.active_cubes = self
+            .count_active_neighbours()
+            .into_iter()
+            .filter(|(cube, active_neighbours)| {
+                *active_neighbours == 3
+                    || (*active_neighbours == 2 && self.active_cubes.contains(cube))
+            })
+            .map(|(cube, _active_neighbours)| cube)
+            .collect();
+    }
+
+    fn active_cube_count(&self) -> usize {
+        self.active_cubes.len()
+    }
+}
+
+fn initial_slice_from_strings(slice_strings: &[String]) -> anyhow::Result<Vec<CubeCoordinate>> {
+    let mut active_cubes: Vec<CubeCoordinate> = Vec::new();
+
+    for (y, row) in slice_strings.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            match c {
+                '#' => active_cubes.push(CubeCoordinate::new(vec![x as i64, y as i64])),
+                '.' => {}
+                _ => {
+                    return Err(anyhow::Error::msg(format!(
+                        "Could not parse cube state '{}'",
+                        c
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(active_cubes)
+}
+
+fn pocket_dimension_from_initial_slice(
+    initial_slice: Vec<CubeCoordinate>,
+    dimensions: usize,
+) -> anyhow::Result<PocketDimension> {
+    if dimensions < 2 {
+        return Err(anyhow::Error::msg(
+            "Pocket dimension must have at least two dimensions",
+        ));
+    }
+
+    let active_cubes = initial_slice
+        .into_iter()
+        .map(|CubeCoordinate(mut components)| {
+            components.resize(dimensions, 0);
+            CubeCoordinate::new(components)
+        })
+        .collect();
+
+    Ok(PocketDimension::new(active_cubes))
+}
+
+fn count_active_cubes_after_boot_cycles_in_dimensions(
+    initial_slice: Vec<CubeCoordinate>,
+    dimensions: usize,
+) -> anyhow::Result<usize> {
+    let mut pocket_dimension = pocket_dimension_from_initial_slice(initial_slice, dimensions)?;
+
+    (0..6).for_each(|_cycle| pocket_dimension.run_cycle());
+
+    Ok(pocket_dimension.active_cube_count())
+}
+
+pub fn count_active_cubes_after_3d_boot_cycles(
+    slice_strings: Vec<String>,
+) -> anyhow::Result<usize> {
+    count_active_cubes_after_boot_cycles_in_dimensions(
+        initial_slice_from_strings(&slice_strings)?,
+        3,
+    )
+}
+
+pub fn count_active_cubes_after_4d_boot_cycles(
+    slice_strings: Vec<String>,
+) -> anyhow::Result<usize> {
+    count_active_cubes_after_boot_cycles_in_dimensions(
+        initial_slice_from_strings(&slice_strings)?,
+        4,
+    )
+}
+
+pub struct Day17Solver;
+
+impl Solver for Day17Solver {
+    type Input = Vec<CubeCoordinate>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        initial_slice_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let dimensions = match part {
+            ChallengePart::One => 3,
+            ChallengePart::Two => 4,
+        };
+
+        count_active_cubes_after_boot_cycles_in_dimensions(input, dimensions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn initial_slice_strings() -> Vec<String> {
+        [".#.", "..#", "###"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn counts_active_cubes_after_3d_boot_cycles() {
+        assert_that(&count_active_cubes_after_3d_boot_cycles(initial_slice_strings()).unwrap())
+            .is_equal_to(112);
+    }
+
+    #[test]
+    fn counts_active_cubes_after_4d_boot_cycles() {
+        assert_that(&count_active_cubes_after_4d_boot_cycles(initial_slice_strings()).unwrap())
+            .is_equal_to(848);
+    }
+
+    #[test]
+    fn finds_every_neighbour_of_a_cube() {
+        assert_that(&CubeCoordinate::new(vec![0, 0, 0, 0]).neighbours().len()).is_equal_to(80);
+    }
+}
@@ -0,0 +1,431 @@
+use std::str::FromStr;
+
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Multiply => left.checked_mul(right),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+enum Token {
+    Number(u64),
+    Operator(Operator),
+    OpenParenthesis,
+    CloseParenthesis,
+}
+
+#[derive(Copy, Clone)]
+struct PositionedToken {
+    token: Token,
+    column: usize,
+}
+
+impl PositionedToken {
+    fn new(token: Token, column: usize) -> Self {
+        PositionedToken { token, column }
+    }
+
+    fn token(&self) -> Token {
+        self.token
+    }
+
+    fn column(&self) -> usize {
+        self.column
+    }
+}
+
+fn tokenise(s: &str) -> anyhow::Result<Vec<PositionedToken>> {
+    let mut tokens: Vec<PositionedToken> = vec![];
+    let mut characters = s.chars().enumerate().peekable();
+
+    while let Some((index, c)) = characters.next() {
+        let column = index + 1;
+        let token = match c {
+            ' ' => continue,
+            '+' => Token::Operator(Operator::Add),
+            '*' => Token::Operator(Operator::Multiply),
+            '(' => Token::OpenParenthesis,
+            ')' => Token::CloseParenthesis,
+            '0'..='9' => {
+                let mut digits = c.to_string();
+                while let Some((_index, digit)) =
+                    characters.next_if(|(_index, c)| c.is_ascii_digit())
+                {
+                    digits.push(digit);
+                }
+                Token::Number(digits.parse().map_err(|_| {
+                    anyhow::Error::msg(format!("Number at column {} is too large", column))
+                })?)
+            }
+            _ => {
+                return Err(anyhow::Error::msg(format!(
+                    "Unexpected character '{}' at column {}",
+                    c, column
+                )))
+            }
+        };
+        tokens.push(PositionedToken::new(token, column));
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Copy, Clone)]
+struct OperatorPrecedence {
+    add: u8,
+    multiply: u8,
+}
+
+impl OperatorPrecedence {
+    fn equal() -> Self {
+        OperatorPrecedence {
+            add: 1,
+            multiply: 1,
+        }
+    }
+
+    fn addition_before_multiplication() -> Self {
+        OperatorPrecedence {
+            add: 2,
+            multiply: 1,
+        }
+    }
+
+    fn of(&self, operator: Operator) -> u8 {
+        match operator {
+            Operator::Add => self.add,
+            Operator::Multiply => self.multiply,
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+enum Expression {
+    Number(u64),
+    Operation {
+        operator: Operator,
+        left: Box<Expression>,
+        right: Box<Expression>,
+    },
+}
+
+impl Expression {
+    fn evaluate(&self) -> anyhow::Result<u64> {
+        match self {
+            Expression::Number(n) => Ok(*n),
+            Expression::Operation {
+                operator,
+                left,
+                right,
+            } => operator
+                .apply(left.evaluate()?, right.evaluate()?)
+                .ok_or_else(|| anyhow::Error::msg("Expression value overflowed")),
+        }
+    }
+}
+
+struct ExpressionParser {
+    tokens: Vec<PositionedToken>,
+    position: usize,
+    precedence: OperatorPrecedence,
+    end_column: usize,
+}
+
+impl ExpressionParser {
+    fn new(
+        tokens: Vec<PositionedToken>,
+        precedence: OperatorPrecedence,
+        end_column: usize,
+    ) -> Self {
+        ExpressionParser {
+            tokens,
+            position: 0,
+            precedence,
+            end_column,
+        }
+    }
+
+    fn peek(&self) -> Option<PositionedToken> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> anyhow::Result<PositionedToken> {
+        let token = self.peek().ok_or_else(|| {
+            anyhow::Error::msg(format!(
+                "Unexpected end of expression at column {}",
+                self.end_column
+            ))
+        })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn parse(&mut self) -> anyhow::Result<Expression> {
+        let expression = self.parse_expression(0)?;
+
+        match self.peek() {
+            Some(token) => Err(unexpected_token_error(token)),
+            None => Ok(expression),
+        }
+    }
+
+    fn parse_expression(&mut self, minimum_precedence: u8) -> anyhow::Result<Expression> {
+        let mut left = self.parse_operand()?;
+
+        while let Some(positioned_token) = self.peek() {
+            let operator = match positioned_token.token() {
+                Token::Operator(operator) => operator,
+                _ => break,
+            };
+            let precedence = self.precedence.of(operator);
+            if precedence < minimum_precedence {
+                break;
+            }
+
+            self.position += 1;
+            let right = self.parse_expression(precedence + 1)?;
+            left = Expression::Operation {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    fn parse_operand(&mut self) -> anyhow::Result<Expression> {
+        let positioned_token = self.next()?;
+
+        match positioned_token.token() {
+            Token::Number(n) => Ok(Expression::Number(n)),
+            Token::OpenParenthesis => {
+                let expression = self.parse_expression(0)?;
+                let closing_token = self.next()?;
+                match closing_token.token() {
+                    Token::CloseParenthesis => Ok(expression),
+                    _ => Err(unexpected_token_error(closing_token)),
+                }
+            }
+            _ => Err(unexpected_token_error(positioned_token)),
+        }
+    }
+}
+
+fn unexpected_token_error(positioned_token: PositionedToken) -> anyhow::Error {
+    anyhow::Error::msg(format!(
+        "Unexpected token at column {}",
+        positioned_token.column()
+    ))
+}
+
+pub struct TokenisedExpression {
+    tokens: Vec<PositionedToken>,
+    end_column: usize,
+}
+
+impl TokenisedExpression {
+    fn new(tokens: Vec<PositionedToken>, end_column: usize) -> Self {
+        TokenisedExpression { tokens, end_column }
+    }
+
+    fn parse(&self, precedence: OperatorPrecedence) -> anyhow::Result<Expression> {
+        ExpressionParser::new(self.tokens.clone(), precedence, self.end_column).parse()
+    }
+}
+
+impl FromStr for TokenisedExpression {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(TokenisedExpression::new(
+            tokenise(s)?,
+            s.chars().count() + 1,
+        ))
+    }
+}
+
+fn line_error(index: usize, error: anyhow::Error) -> anyhow::Error {
+    anyhow::Error::msg(format!("Line {}: {}", index + 1, error))
+}
+
+fn tokenised_expressions_from_strings(
+    expression_strings: &[String],
+) -> anyhow::Result<Vec<TokenisedExpression>> {
+    expression_strings
+        .iter()
+        .enumerate()
+        .map(|(index, s)| s.parse().map_err(|e| line_error(index, e)))
+        .collect()
+}
+
+fn sum_of_expression_values_with_precedence(
+    expressions: &[TokenisedExpression],
+    precedence: OperatorPrecedence,
+) -> anyhow::Result<u64> {
+    expressions
+        .iter()
+        .enumerate()
+        .try_fold(0_u64, |sum, (index, expression)| {
+            let value = expression
+                .parse(precedence)
+                .and_then(|expression| expression.evaluate())
+                .map_err(|e| line_error(index, e))?;
+            sum.checked_add(value)
+                .ok_or_else(|| anyhow::Error::msg("Sum of expression values overflowed"))
+        })
+}
+
+pub fn sum_of_expression_values_with_equal_precedence(
+    expression_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    sum_of_expression_values_with_precedence(
+        &tokenised_expressions_from_strings(&expression_strings)?,
+        OperatorPrecedence::equal(),
+    )
+}
+
+pub fn sum_of_expression_values_with_addition_precedence(
+    expression_strings: Vec<String>,
+) -> anyhow::Result<u64> {
+    sum_of_expression_values_with_precedence(
+        &tokenised_expressions_from_strings(&expression_strings)?,
+        OperatorPrecedence::addition_before_multiplication(),
+    )
+}
+
+pub struct Day18Solver;
+
+impl Solver for Day18Solver {
+    type Input = Vec<TokenisedExpression>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        tokenised_expressions_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let precedence = match part {
+            ChallengePart::One => OperatorPrecedence::equal(),
+            ChallengePart::Two => OperatorPrecedence::addition_before_multiplication(),
+        };
+
+        sum_of_expression_values_with_precedence(&input, precedence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn parse_expression(s: &str, precedence: OperatorPrecedence) -> anyhow::Result<Expression> {
+        s.parse::<TokenisedExpression>()?.parse(precedence)
+    }
+
+    fn evaluate(s: &str, precedence: OperatorPrecedence) -> anyhow::Result<u64> {
+        parse_expression(s, precedence)?.evaluate()
+    }
+
+    #[test]
+    fn evaluates_expressions_with_equal_precedence() {
+        let precedence = OperatorPrecedence::equal();
+
+        assert_that(&evaluate("1 + 2 * 3 + 4 * 5 + 6", precedence).unwrap()).is_equal_to(71);
+        assert_that(&evaluate("2 * 3 + (4 * 5)", precedence).unwrap()).is_equal_to(26);
+        assert_that(
+            &evaluate(
+                "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+                precedence,
+            )
+            .unwrap(),
+        )
+        .is_equal_to(13632);
+    }
+
+    #[test]
+    fn evaluates_expressions_with_addition_precedence() {
+        let precedence = OperatorPrecedence::addition_before_multiplication();
+
+        assert_that(&evaluate("1 + 2 * 3 + 4 * 5 + 6", precedence).unwrap()).is_equal_to(231);
+        assert_that(&evaluate("5 + (8 * 3 + 9 + 3 * 4 * 3)", precedence).unwrap())
+            .is_equal_to(1445);
+        assert_that(
+            &evaluate(
+                "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+                precedence,
+            )
+            .unwrap(),
+        )
+        .is_equal_to(23340);
+    }
+
+    #[test]
+    fn sums_expression_values() {
+        let expression_strings = ["2 * 3 + (4 * 5)", "5 + (8 * 3 + 9 + 3 * 4 * 3)"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_that(&sum_of_expression_values_with_equal_precedence(expression_strings).unwrap())
+            .is_equal_to(463);
+    }
+
+    #[test]
+    fn parses_expression_into_tree() {
+        assert_that(&parse_expression("1 + 2 * 3", OperatorPrecedence::equal()).unwrap())
+            .is_equal_to(Expression::Operation {
+                operator: Operator::Multiply,
+                left: Box::new(Expression::Operation {
+                    operator: Operator::Add,
+                    left: Box::new(Expression::Number(1)),
+                    right: Box::new(Expression::Number(2)),
+                }),
+                right: Box::new(Expression::Number(3)),
+            });
+    }
+
+    #[test]
+    fn reports_column_of_unexpected_token() {
+        let error = evaluate("1 + (2 * ) 3", OperatorPrecedence::equal()).unwrap_err();
+
+        assert_that(&error.to_string()).is_equal_to("Unexpected token at column 10".to_string());
+    }
+
+    #[test]
+    fn reports_column_of_unclosed_parenthesis() {
+        let error = evaluate("(1 + 2", OperatorPrecedence::equal()).unwrap_err();
+
+        assert_that(&error.to_string())
+            .is_equal_to("Unexpected end of expression at column 7".to_string());
+    }
+
+    #[test]
+    fn fails_to_evaluate_overflowing_expression() {
+        assert_that(&evaluate(
+            "18446744073709551615 + 1",
+            OperatorPrecedence::equal(),
+        ))
+        .is_err();
+    }
+}
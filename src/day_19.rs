@@ -0,0 +1,418 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+enum Rule {
+    Character(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+impl FromStr for Rule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref CHARACTER_REGEX: Regex = Regex::new(r#"^"(?P<character>.)"$"#).unwrap();
+        }
+
+        if let Some(captures) = CHARACTER_REGEX.captures(s) {
+            let character = captures.name("character").unwrap().as_str();
+            return Ok(Rule::Character(character.chars().next().unwrap()));
+        }
+
+        let alternatives = s
+            .split('|')
+            .map(|alternative| {
+                alternative
+                    .split_whitespace()
+                    .map(|rule_id| rule_id.parse())
+                    .collect::<Result<Vec<usize>, _>>()
+            })
+            .collect::<Result<Vec<Vec<usize>>, _>>()
+            .map_err(|_| anyhow::Error::msg(format!("Could not parse rule '{}'", s)))?;
+
+        if alternatives.iter().any(Vec::is_empty) {
+            return Err(anyhow::Error::msg(format!(
+                "Rule '{}' contains an empty alternative",
+                s
+            )));
+        }
+
+        Ok(Rule::Alternatives(alternatives))
+    }
+}
+
+fn numbered_rule_from_string(s: &str) -> anyhow::Result<(usize, Rule)> {
+    let mut splits = s.splitn(2, ": ");
+    let rule_id = splits.next().unwrap().parse()?;
+    let rule = splits
+        .next()
+        .ok_or_else(|| anyhow::Error::msg(format!("Could not parse numbered rule '{}'", s)))?
+        .parse()?;
+
+    Ok((rule_id, rule))
+}
+
+pub struct RuleGrammar {
+    rules: HashMap<usize, Rule>,
+}
+
+impl RuleGrammar {
+    fn new(rules: HashMap<usize, Rule>) -> Self {
+        RuleGrammar { rules }
+    }
+
+    fn replace_rule(&mut self, rule_string: &str) -> anyhow::Result<()> {
+        let (rule_id, rule) = numbered_rule_from_string(rule_string)?;
+        self.rules.insert(rule_id, rule);
+        Ok(())
+    }
+
+    fn validate(&self, root_rule_id: usize) -> anyhow::Result<()> {
+        if !self.rules.contains_key(&root_rule_id) {
+            return Err(anyhow::Error::msg(format!(
+                "Rule {} is not defined",
+                root_rule_id
+            )));
+        }
+
+        self.rules
+            .values()
+            .filter_map(|rule| match rule {
+                Rule::Alternatives(alternatives) => Some(alternatives.iter().flatten()),
+                Rule::Character(_) => None,
+            })
+            .flatten()
+            .try_for_each(|rule_id| {
+                if self.rules.contains_key(rule_id) {
+                    Ok(())
+                } else {
+                    Err(anyhow::Error::msg(format!(
+                        "Rule {} is referenced but not defined",
+                        rule_id
+                    )))
+                }
+            })?;
+
+        let mut rule_ids: Vec<usize> = self.rules.keys().copied().collect();
+        rule_ids.sort_unstable();
+        let mut checked_rule_ids = HashSet::new();
+        rule_ids.into_iter().try_for_each(|rule_id| {
+            self.validate_not_left_recursive(rule_id, &mut vec![], &mut checked_rule_ids)
+        })
+    }
+
+    fn validate_not_left_recursive(
+        &self,
+        rule_id: usize,
+        leftmost_path: &mut Vec<usize>,
+        checked_rule_ids: &mut HashSet<usize>,
+    ) -> anyhow::Result<()> {
+        if checked_rule_ids.contains(&rule_id) {
+            return Ok(());
+        }
+        if leftmost_path.contains(&rule_id) {
+            return Err(anyhow::Error::msg(format!(
+                "Rule {} is left-recursive",
+                rule_id
+            )));
+        }
+
+        leftmost_path.push(rule_id);
+        if let Some(Rule::Alternatives(alternatives)) = self.rules.get(&rule_id) {
+            alternatives
+                .iter()
+                .filter_map(|sequence| sequence.first())
+                .try_for_each(|first_rule_id| {
+                    self.validate_not_left_recursive(
+                        *first_rule_id,
+                        leftmost_path,
+                        checked_rule_ids,
+                    )
+                })?;
+        }
+        leftmost_path.pop();
+        checked_rule_ids.insert(rule_id);
+
+        Ok(())
+    }
+
+    fn match_ends(&self, rule_id: usize, message: &[char], start: usize) -> BTreeSet<usize> {
+        if start >= message.len() {
+            return BTreeSet::new();
+        }
+
+        match self.rules.get(&rule_id).unwrap() {
+            Rule::Character(c) => {
+                if message[start] == *c {
+                    BTreeSet::from([start + 1])
+                } else {
+                    BTreeSet::new()
+                }
+            }
+            Rule::Alternatives(alternatives) => alternatives
+                .iter()
+                .flat_map(|sequence| self.sequence_match_ends(sequence, message, start))
+                .collect(),
+        }
+    }
+
+    fn sequence_match_ends(
+        &self,
+        sequence: &[usize],
+        message: &[char],
+        start: usize,
+    ) -> BTreeSet<usize> {
+        sequence
+            .iter()
+            .fold(BTreeSet::from([start]), |ends, rule_id| {
+                ends.iter()
+                    .flat_map(|end| self.match_ends(*rule_id, message, *end))
+                    .collect()
+            })
+    }
+
+    fn matches(&self, root_rule_id: usize, message: &str) -> bool {
+        let message: Vec<char> = message.chars().collect();
+
+        self.match_ends(root_rule_id, &message, 0)
+            .contains(&message.len())
+    }
+}
+
+fn parse_input_records(input_records: Vec<String>) -> anyhow::Result<(RuleGrammar, Vec<String>)> {
+    let (rules_record, messages_record) = match input_records.as_slice() {
+        [rules_record, messages_record] => (rules_record, messages_record),
+        _ => {
+            return Err(anyhow::Error::msg(format!(
+                "Expected 2 input sections but found {}",
+                input_records.len()
+            )))
+        }
+    };
+
+    let rules = rules_record
+        .lines()
+        .map(numbered_rule_from_string)
+        .collect::<anyhow::Result<HashMap<usize, Rule>>>()?;
+    let messages = messages_record.lines().map(ToString::to_string).collect();
+
+    Ok((RuleGrammar::new(rules), messages))
+}
+
+fn count_messages_matching_rule_0(
+    rule_grammar: RuleGrammar,
+    messages: Vec<String>,
+) -> anyhow::Result<usize> {
+    rule_grammar.validate(0)?;
+
+    Ok(messages
+        .iter()
+        .filter(|message| rule_grammar.matches(0, message))
+        .count())
+}
+
+pub fn count_messages_matching_rule_0_of_input(
+    input_records: Vec<String>,
+) -> anyhow::Result<usize> {
+    let (rule_grammar, messages) = parse_input_records(input_records)?;
+
+    count_messages_matching_rule_0(rule_grammar, messages)
+}
+
+fn count_messages_matching_rule_0_with_looping_rules(
+    mut rule_grammar: RuleGrammar,
+    messages: Vec<String>,
+) -> anyhow::Result<usize> {
+    rule_grammar.replace_rule("8: 42 | 42 8")?;
+    rule_grammar.replace_rule("11: 42 31 | 42 11 31")?;
+
+    count_messages_matching_rule_0(rule_grammar, messages)
+}
+
+pub fn count_messages_matching_rule_0_of_input_with_looping_rules(
+    input_records: Vec<String>,
+) -> anyhow::Result<usize> {
+    let (rule_grammar, messages) = parse_input_records(input_records)?;
+
+    count_messages_matching_rule_0_with_looping_rules(rule_grammar, messages)
+}
+
+pub struct Day19Solver;
+
+impl Solver for Day19Solver {
+    type Input = (RuleGrammar, Vec<String>);
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        parse_input_records(input.records())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let (rule_grammar, messages) = input;
+
+        match part {
+            ChallengePart::One => count_messages_matching_rule_0(rule_grammar, messages),
+            ChallengePart::Two => {
+                count_messages_matching_rule_0_with_looping_rules(rule_grammar, messages)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn records_from_lines(lines: &[&str]) -> Vec<String> {
+        ChallengeInput::new(&lines.join("\n")).records()
+    }
+
+    fn looping_input_records() -> Vec<String> {
+        records_from_lines(&[
+            "42: 9 14 | 10 1",
+            "9: 14 27 | 1 26",
+            "10: 23 14 | 28 1",
+            "1: \"a\"",
+            "11: 42 31",
+            "5: 1 14 | 15 1",
+            "19: 14 1 | 14 14",
+            "12: 24 14 | 19 1",
+            "16: 15 1 | 14 14",
+            "31: 14 17 | 1 13",
+            "6: 14 14 | 1 14",
+            "2: 1 24 | 14 4",
+            "0: 8 11",
+            "13: 14 3 | 1 12",
+            "15: 1 | 14",
+            "17: 14 2 | 1 7",
+            "23: 25 1 | 22 14",
+            "28: 16 1",
+            "4: 1 1",
+            "20: 14 14 | 1 15",
+            "3: 5 14 | 16 1",
+            "27: 1 6 | 14 18",
+            "14: \"b\"",
+            "21: 14 1 | 1 14",
+            "25: 1 1 | 1 14",
+            "22: 14 14",
+            "8: 42",
+            "26: 14 22 | 1 20",
+            "18: 15 15",
+            "7: 14 5 | 1 21",
+            "24: 14 1",
+            "",
+            "abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa",
+            "bbabbbbaabaabba",
+            "babbbbaabbbbbabbbbbbaabaaabaaa",
+            "aaabbbbbbaaaabaababaabababbabaaabbababababaaa",
+            "bbbbbbbaaaabbbbaaabbabaaa",
+            "bbbababbbbaaaaaaaabbababaaababaabab",
+            "ababaaaaaabaaab",
+            "ababaaaaabbbaba",
+            "baabbaaaabbaaaababbaababb",
+            "abbbbabbbbaaaababbbbbbaaaababb",
+            "aaaaabbaabaaaaababaa",
+            "aaaabbaaaabbaaa",
+            "aaaabbaabbaaaaaaabbbabbbaaabbaabaaa",
+            "babaaabbbaaabaababbaabababaaab",
+            "aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba",
+        ])
+    }
+
+    #[test]
+    fn counts_messages_matching_rule_0() {
+        let input_records = records_from_lines(&[
+            "0: 4 1 5",
+            "1: 2 3 | 3 2",
+            "2: 4 4 | 5 5",
+            "3: 4 5 | 5 4",
+            "4: \"a\"",
+            "5: \"b\"",
+            "",
+            "ababbb",
+            "bababa",
+            "abbbab",
+            "aaabbb",
+            "aaaabbb",
+        ]);
+
+        assert_that(&count_messages_matching_rule_0_of_input(input_records).unwrap())
+            .is_equal_to(2);
+    }
+
+    #[test]
+    fn counts_messages_matching_rule_0_without_looping_rules() {
+        assert_that(&count_messages_matching_rule_0_of_input(looping_input_records()).unwrap())
+            .is_equal_to(3);
+    }
+
+    #[test]
+    fn counts_messages_matching_rule_0_with_looping_rules() {
+        assert_that(
+            &count_messages_matching_rule_0_of_input_with_looping_rules(looping_input_records())
+                .unwrap(),
+        )
+        .is_equal_to(12);
+    }
+
+    #[test]
+    fn parses_rule_alternatives() {
+        assert_that(&"4 1 | 1 4".parse::<Rule>().unwrap())
+            .is_equal_to(Rule::Alternatives(vec![vec![4, 1], vec![1, 4]]));
+    }
+
+    #[test]
+    fn fails_to_match_against_left_recursive_rule() {
+        let input_records = records_from_lines(&["0: 8 1", "8: 1 | 8 1", "1: \"a\"", "", "aaa"]);
+
+        assert_that(&count_messages_matching_rule_0_of_input(input_records)).is_err();
+    }
+
+    #[test]
+    fn matches_ambiguous_rules_without_repeating_end_positions() {
+        let input_records = records_from_lines(&[
+            "0: 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 2 3",
+            "2: 1 | 1 1",
+            "1: \"a\"",
+            "3: \"b\"",
+            "",
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        ]);
+
+        assert_that(&count_messages_matching_rule_0_of_input(input_records).unwrap())
+            .is_equal_to(0);
+    }
+
+    #[test]
+    fn fails_to_match_against_undefined_rule() {
+        let input_records = records_from_lines(&["0: 1 2", "1: \"a\"", "", "ab"]);
+
+        assert_that(&count_messages_matching_rule_0_of_input(input_records)).is_err();
+    }
+
+    #[test]
+    fn fails_to_parse_input_without_messages_section() {
+        let input_records = records_from_lines(&["0: 1 1", "1: \"a\""]);
+
+        assert_that(
+            &parse_input_records(input_records)
+                .err()
+                .map(|error| error.to_string()),
+        )
+        .is_equal_to(Some("Expected 2 input sections but found 1".to_string()));
+    }
+}
@@ -0,0 +1,686 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+const SEA_MONSTER_PATTERN: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+struct PixelGrid {
+    pixels: Vec<Vec<bool>>,
+}
+
+impl PixelGrid {
+    fn new(pixels: Vec<Vec<bool>>) -> Self {
+        PixelGrid { pixels }
+    }
+
+    fn size(&self) -> usize {
+        self.pixels.len()
+    }
+
+    fn pixel(&self, row: usize, column: usize) -> bool {
+        self.pixels[row][column]
+    }
+
+    fn rotated(&self) -> Self {
+        let size = self.size();
+        PixelGrid::new(
+            (0..size)
+                .map(|row| {
+                    (0..size)
+                        .map(|column| self.pixel(size - 1 - column, row))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    fn flipped(&self) -> Self {
+        PixelGrid::new(
+            self.pixels
+                .iter()
+                .map(|row| row.iter().rev().copied().collect())
+                .collect(),
+        )
+    }
+
+    fn orientations(&self) -> Vec<Self> {
+        let mut orientations = vec![];
+        let mut grid = self.clone();
+
+        for _flip in 0..2 {
+            for _rotation in 0..4 {
+                let rotated = grid.rotated();
+                orientations.push(grid);
+                grid = rotated;
+            }
+            grid = grid.flipped();
+        }
+
+        orientations
+    }
+
+    fn top_edge(&self) -> Vec<bool> {
+        self.pixels.first().unwrap().clone()
+    }
+
+    fn bottom_edge(&self) -> Vec<bool> {
+        self.pixels.last().unwrap().clone()
+    }
+
+    fn left_edge(&self) -> Vec<bool> {
+        self.pixels
+            .iter()
+            .map(|row| *row.first().unwrap())
+            .collect()
+    }
+
+    fn right_edge(&self) -> Vec<bool> {
+        self.pixels.iter().map(|row| *row.last().unwrap()).collect()
+    }
+
+    fn edges(&self) -> Vec<Vec<bool>> {
+        vec![
+            self.top_edge(),
+            self.right_edge(),
+            self.bottom_edge(),
+            self.left_edge(),
+        ]
+    }
+
+    fn without_border(&self) -> Self {
+        let size = self.size();
+        PixelGrid::new(
+            self.pixels[1..(size - 1)]
+                .iter()
+                .map(|row| row[1..(size - 1)].to_vec())
+                .collect(),
+        )
+    }
+
+    fn active_pixel_count(&self) -> usize {
+        self.pixels.iter().flatten().filter(|pixel| **pixel).count()
+    }
+}
+
+impl Display for PixelGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in &self.pixels {
+            let line: String = row
+                .iter()
+                .map(|pixel| if *pixel { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn canonical_edge(edge: &[bool]) -> Vec<bool> {
+    let reversed: Vec<bool> = edge.iter().rev().copied().collect();
+    if reversed.as_slice() < edge {
+        reversed
+    } else {
+        edge.to_vec()
+    }
+}
+
+#[derive(Clone)]
+pub struct Tile {
+    id: u64,
+    grid: PixelGrid,
+}
+
+impl Tile {
+    fn new(id: u64, grid: PixelGrid) -> Self {
+        Tile { id, grid }
+    }
+
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn grid(&self) -> &PixelGrid {
+        &self.grid
+    }
+
+    fn orientations(&self) -> Vec<Self> {
+        self.grid
+            .orientations()
+            .into_iter()
+            .map(|grid| Tile::new(self.id, grid))
+            .collect()
+    }
+}
+
+fn tile_from_record(tile_record: &str) -> anyhow::Result<Tile> {
+    lazy_static! {
+        static ref TITLE_REGEX: Regex = Regex::new(r"^Tile (?P<id>\d+):$").unwrap();
+    }
+
+    let lines: Vec<&str> = tile_record.lines().collect();
+    let title = lines
+        .first()
+        .ok_or_else(|| anyhow::Error::msg("Could not parse empty tile"))?;
+    let id = TITLE_REGEX
+        .captures(title)
+        .ok_or_else(|| anyhow::Error::msg(format!("Could not parse tile title '{}'", title)))?
+        .name("id")
+        .unwrap()
+        .as_str()
+        .parse()?;
+
+    let pixels = lines[1..]
+        .iter()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(anyhow::Error::msg(format!(
+                        "Could not parse pixel '{}' of tile {}",
+                        c, id
+                    ))),
+                })
+                .collect::<anyhow::Result<Vec<bool>>>()
+        })
+        .collect::<anyhow::Result<Vec<Vec<bool>>>>()?;
+
+    if pixels.len() < 3 || pixels.iter().any(|row| row.len() != pixels.len()) {
+        return Err(anyhow::Error::msg(format!("Tile {} is not square", id)));
+    }
+
+    Ok(Tile::new(id, PixelGrid::new(pixels)))
+}
+
+fn tiles_from_records(tile_records: Vec<String>) -> anyhow::Result<Vec<Tile>> {
+    tile_records
+        .iter()
+        .map(|tile_record| tile_from_record(tile_record))
+        .collect()
+}
+
+struct TileAssembler {
+    tiles: Vec<Tile>,
+    edge_occurrences: HashMap<Vec<bool>, usize>,
+}
+
+impl TileAssembler {
+    fn new(tiles: Vec<Tile>) -> Self {
+        let mut edge_occurrences: HashMap<Vec<bool>, usize> = HashMap::new();
+        tiles.iter().for_each(|tile| {
+            tile.grid().edges().iter().for_each(|edge| {
+                *edge_occurrences.entry(canonical_edge(edge)).or_insert(0) += 1;
+            })
+        });
+
+        TileAssembler {
+            tiles,
+            edge_occurrences,
+        }
+    }
+
+    fn is_outer_edge(&self, edge: &[bool]) -> bool {
+        self.edge_occurrences.get(&canonical_edge(edge)) == Some(&1)
+    }
+
+    fn corner_tiles(&self) -> Vec<&Tile> {
+        self.tiles
+            .iter()
+            .filter(|tile| {
+                tile.grid()
+                    .edges()
+                    .iter()
+                    .filter(|edge| self.is_outer_edge(edge))
+                    .count()
+                    == 2
+            })
+            .collect()
+    }
+
+    fn side_length(&self) -> anyhow::Result<usize> {
+        let side_length = (self.tiles.len() as f64).sqrt().round() as usize;
+        if side_length == 0 || side_length * side_length != self.tiles.len() {
+            return Err(anyhow::Error::msg(format!(
+                "{} tiles cannot form a square image",
+                self.tiles.len()
+            )));
+        }
+        Ok(side_length)
+    }
+
+    fn assemble(&self) -> anyhow::Result<Vec<Vec<Tile>>> {
+        let side_length = self.side_length()?;
+        let oriented_tiles: Vec<Vec<Tile>> = self.tiles.iter().map(Tile::orientations).collect();
+
+        let mut placed: Vec<Tile> = vec![];
+        let mut used: Vec<bool> = vec![false; self.tiles.len()];
+
+        if self.place_next_tile(side_length, &oriented_tiles, &mut placed, &mut used) {
+            Ok(placed.chunks(side_length).map(|row| row.to_vec()).collect())
+        } else {
+            Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+                "Could not assemble tiles into an image".to_string(),
+            )))
+        }
+    }
+
+    fn fits(&self, side_length: usize, placed: &[Tile], candidate: &Tile) -> bool {
+        let position = placed.len();
+        let row = position / side_length;
+        let column = position % side_length;
+
+        let fits_left = if column == 0 {
+            self.is_outer_edge(&candidate.grid().left_edge())
+        } else {
+            placed[position - 1].grid().right_edge() == candidate.grid().left_edge()
+        };
+        let fits_above = if row == 0 {
+            self.is_outer_edge(&candidate.grid().top_edge())
+        } else {
+            placed[position - side_length].grid().bottom_edge() == candidate.grid().top_edge()
+        };
+
+        fits_left && fits_above
+    }
+
+    fn place_next_tile(
+        &self,
+        side_length: usize,
+        oriented_tiles: &[Vec<Tile>],
+        placed: &mut Vec<Tile>,
+        used: &mut Vec<bool>,
+    ) -> bool {
+        if placed.len() == oriented_tiles.len() {
+            return true;
+        }
+
+        for (index, orientations) in oriented_tiles.iter().enumerate() {
+            if used[index] {
+                continue;
+            }
+
+            for candidate in orientations {
+                if self.fits(side_length, placed, candidate) {
+                    used[index] = true;
+                    placed.push(candidate.clone());
+
+                    if self.place_next_tile(side_length, oriented_tiles, placed, used) {
+                        return true;
+                    }
+
+                    placed.pop();
+                    used[index] = false;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+fn stitch_image(assembled_tiles: &[Vec<Tile>]) -> PixelGrid {
+    let pixels = assembled_tiles
+        .iter()
+        .flat_map(|tile_row| {
+            let inner_grids: Vec<PixelGrid> = tile_row
+                .iter()
+                .map(|tile| tile.grid().without_border())
+                .collect();
+            let inner_size = inner_grids.first().unwrap().size();
+
+            (0..inner_size)
+                .map(|row| {
+                    inner_grids
+                        .iter()
+                        .flat_map(|grid| grid.pixels[row].clone())
+                        .collect::<Vec<bool>>()
+                })
+                .collect::<Vec<Vec<bool>>>()
+        })
+        .collect();
+
+    PixelGrid::new(pixels)
+}
+
+struct SeaMonsterSearch {
+    offsets: Vec<(usize, usize)>,
+    height: usize,
+    width: usize,
+}
+
+impl SeaMonsterSearch {
+    fn new() -> Self {
+        let offsets = SEA_MONSTER_PATTERN
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_column, c)| *c == '#')
+                    .map(move |(column, _c)| (row, column))
+            })
+            .collect();
+
+        SeaMonsterSearch {
+            offsets,
+            height: SEA_MONSTER_PATTERN.len(),
+            width: SEA_MONSTER_PATTERN.first().unwrap().len(),
+        }
+    }
+
+    fn sea_monster_pixels(&self, image: &PixelGrid) -> HashSet<(usize, usize)> {
+        let mut pixels = HashSet::new();
+
+        if image.size() < self.height || image.size() < self.width {
+            return pixels;
+        }
+
+        for row in 0..=(image.size() - self.height) {
+            for column in 0..=(image.size() - self.width) {
+                if self.offsets.iter().all(|(row_offset, column_offset)| {
+                    image.pixel(row + row_offset, column + column_offset)
+                }) {
+                    self.offsets.iter().for_each(|(row_offset, column_offset)| {
+                        pixels.insert((row + row_offset, column + column_offset));
+                    });
+                }
+            }
+        }
+
+        pixels
+    }
+
+    fn find_orientation_with_sea_monsters(
+        &self,
+        image: &PixelGrid,
+    ) -> anyhow::Result<(PixelGrid, HashSet<(usize, usize)>)> {
+        image
+            .orientations()
+            .into_iter()
+            .map(|orientation| {
+                let pixels = self.sea_monster_pixels(&orientation);
+                (orientation, pixels)
+            })
+            .find(|(_orientation, pixels)| !pixels.is_empty())
+            .ok_or_else(|| {
+                anyhow::Error::new(ChallengeError::NoSolutionFound(
+                    "Could not find any sea monsters".to_string(),
+                ))
+            })
+    }
+}
+
+fn render_with_sea_monsters(
+    image: &PixelGrid,
+    sea_monster_pixels: &HashSet<(usize, usize)>,
+) -> String {
+    image
+        .pixels
+        .iter()
+        .enumerate()
+        .map(|(row, pixels)| {
+            let mut line: String = pixels
+                .iter()
+                .enumerate()
+                .map(|(column, pixel)| {
+                    if sea_monster_pixels.contains(&(row, column)) {
+                        'O'
+                    } else if *pixel {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+fn assembled_image(tiles: Vec<Tile>) -> anyhow::Result<PixelGrid> {
+    let assembled_tiles = TileAssembler::new(tiles).assemble()?;
+
+    Ok(stitch_image(&assembled_tiles))
+}
+
+fn product_of_corner_ids(tiles: Vec<Tile>) -> anyhow::Result<u64> {
+    let tile_assembler = TileAssembler::new(tiles);
+    let corner_tiles = tile_assembler.corner_tiles();
+
+    if corner_tiles.len() != 4 {
+        return Err(anyhow::Error::msg(format!(
+            "Expected 4 corner tiles but found {}",
+            corner_tiles.len()
+        )));
+    }
+
+    Ok(corner_tiles.iter().map(|tile| tile.id()).product())
+}
+
+fn water_roughness(tiles: Vec<Tile>) -> anyhow::Result<usize> {
+    let image = assembled_image(tiles)?;
+    let (_orientation, sea_monster_pixels) =
+        SeaMonsterSearch::new().find_orientation_with_sea_monsters(&image)?;
+
+    Ok(image.active_pixel_count() - sea_monster_pixels.len())
+}
+
+pub fn product_of_corner_tile_ids(tile_records: Vec<String>) -> anyhow::Result<u64> {
+    product_of_corner_ids(tiles_from_records(tile_records)?)
+}
+
+pub fn water_roughness_of_assembled_image(tile_records: Vec<String>) -> anyhow::Result<usize> {
+    water_roughness(tiles_from_records(tile_records)?)
+}
+
+fn render_image(tiles: Vec<Tile>) -> anyhow::Result<String> {
+    let image = assembled_image(tiles)?;
+    let (orientation, sea_monster_pixels) =
+        SeaMonsterSearch::new().find_orientation_with_sea_monsters(&image)?;
+
+    Ok(render_with_sea_monsters(&orientation, &sea_monster_pixels))
+}
+
+pub fn render_assembled_image(tile_records: Vec<String>) -> anyhow::Result<String> {
+    render_image(tiles_from_records(tile_records)?)
+}
+
+pub struct Day20Solver;
+
+impl Solver for Day20Solver {
+    type Input = Vec<Tile>;
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        tiles_from_records(input.records())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => product_of_corner_ids(input),
+            ChallengePart::Two => Ok(water_roughness(input)? as u64),
+        }
+    }
+
+    fn renders(&self) -> bool {
+        true
+    }
+
+    fn render(&self, _part: ChallengePart, input: Self::Input) -> anyhow::Result<String> {
+        render_image(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    const TILES: &str = "\
+Tile 1533:
+#.#.......
+#.##.###..
+.....#..##
+..#..#....
+##.....###
+#.#..##..#
+...#.#..##
+###..#.#.#
+#...#....#
+#.##..####
+
+Tile 2049:
+##..##.###
+.....##.#.
+##..###..#
+#...##..#.
+....#.####
+.#...#..##
+#.#.#.....
+#.###.....
+......#..#
+.#.####...
+
+Tile 3297:
+...##.#...
+#.#.#..#..
+....##.#.#
+..#.#.....
+#.##..###.
+#.####.#..
+.##..#####
+##.#....##
+.....#....
+###.#..#..
+
+Tile 3451:
+.#...#.#..
+..#.#.#...
+...#.##.##
+..#.#.....
+...##.#..#
+#..#....##
+#.##.##...
+..#..#.#..
+...##.....
+....#..#..
+
+Tile 3580:
+.#....####
+..#...#...
+#.........
+#.#....#..
+###..##.##
+.....#..#.
+#..#..#.#.
+##.....##.
+#.#.#.....
+...#.##...
+
+Tile 1239:
+....#..#..
+...#..##..
+#...###.##
+#..##.....
+..#...#.#.
+..##..#.#.
+...##.#...
+#.#.#.#..#
+..#####.##
+.#.##.#.#.
+
+Tile 2670:
+###.#..#..
+....#.....
+###...#...
+##.#...#.#
+...#.#.#.#
+..#...##..
+###...#...
+#.#..##...
+##.#......
+#####..#..
+
+Tile 1799:
+#.#..##..#
+....##....
+.##......#
+.###.....#
+##...#....
+..#.##..##
+.......#.#
+.....#....
+.....##...
+...####.#.
+
+Tile 3182:
+###.......
+#..#.#...#
+..##...#..
+#........#
+#......#..
+......##.#
+....#.#.##
+##...####.
+....#.#..#
+..###.###.";
+
+    fn tile_records() -> Vec<String> {
+        ChallengeInput::new(TILES).records()
+    }
+
+    #[test]
+    fn gets_product_of_corner_tile_ids() {
+        assert_that(&product_of_corner_tile_ids(tile_records()).unwrap())
+            .is_equal_to(30284363672094);
+    }
+
+    #[test]
+    fn gets_water_roughness_of_assembled_image() {
+        assert_that(&water_roughness_of_assembled_image(tile_records()).unwrap()).is_equal_to(185);
+    }
+
+    #[test]
+    fn renders_assembled_image_with_sea_monsters() {
+        let rendered = render_assembled_image(tile_records()).unwrap();
+
+        assert_that(&rendered.lines().count()).is_equal_to(24);
+        assert_that(&rendered.matches('O').count()).is_equal_to(15);
+    }
+
+    #[test]
+    fn finds_eight_distinct_orientations() {
+        let grid = PixelGrid::new(vec![
+            vec![true, false, false],
+            vec![true, true, false],
+            vec![false, false, false],
+        ]);
+
+        let distinct: HashSet<String> = grid
+            .orientations()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_that(&distinct.len()).is_equal_to(8);
+    }
+}
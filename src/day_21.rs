@@ -0,0 +1,207 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::answer::Answer;
+use crate::challenge::ChallengePart;
+use crate::elimination::resolve_by_elimination;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+#[derive(Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(test, derive(Debug))]
+struct Ingredient(String);
+
+#[derive(Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(test, derive(Debug))]
+struct Allergen(String);
+
+pub struct Food {
+    ingredients: HashSet<Ingredient>,
+    allergens: Vec<Allergen>,
+}
+
+impl Food {
+    fn ingredients(&self) -> &HashSet<Ingredient> {
+        &self.ingredients
+    }
+
+    fn allergens(&self) -> &Vec<Allergen> {
+        &self.allergens
+    }
+}
+
+impl FromStr for Food {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"^(?P<ingredients>[\w ]+?)(?: \(contains (?P<allergens>[\w, ]+)\))?$")
+                    .unwrap();
+        }
+
+        let captures = RE
+            .captures(s)
+            .ok_or_else(|| anyhow::Error::msg(format!("Could not parse food '{}'", s)))?;
+
+        let ingredients = captures
+            .name("ingredients")
+            .unwrap()
+            .as_str()
+            .split_whitespace()
+            .map(|ingredient| Ingredient(ingredient.to_string()))
+            .collect();
+        let allergens = match captures.name("allergens") {
+            Some(allergens) => allergens
+                .as_str()
+                .split(", ")
+                .map(|allergen| Allergen(allergen.to_string()))
+                .collect(),
+            None => vec![],
+        };
+
+        Ok(Food {
+            ingredients,
+            allergens,
+        })
+    }
+}
+
+struct AllergenAssessor {
+    foods: Vec<Food>,
+}
+
+impl AllergenAssessor {
+    fn new(foods: Vec<Food>) -> Self {
+        AllergenAssessor { foods }
+    }
+
+    fn possible_allergen_ingredients(&self) -> HashMap<Allergen, HashSet<Ingredient>> {
+        let mut possible_ingredients: HashMap<Allergen, HashSet<Ingredient>> = HashMap::new();
+
+        self.foods.iter().for_each(|food| {
+            food.allergens().iter().for_each(|allergen| {
+                let ingredients = possible_ingredients
+                    .entry(allergen.clone())
+                    .or_insert_with(|| food.ingredients().clone());
+                ingredients.retain(|ingredient| food.ingredients().contains(ingredient));
+            })
+        });
+
+        possible_ingredients
+    }
+
+    fn count_appearances_of_allergen_free_ingredients(&self) -> usize {
+        let possible_allergen_ingredients: HashSet<Ingredient> = self
+            .possible_allergen_ingredients()
+            .values()
+            .flatten()
+            .cloned()
+            .collect();
+
+        self.foods
+            .iter()
+            .flat_map(Food::ingredients)
+            .filter(|ingredient| !possible_allergen_ingredients.contains(ingredient))
+            .count()
+    }
+
+    fn identify_allergen_ingredients(&self) -> anyhow::Result<HashMap<Allergen, Ingredient>> {
+        resolve_by_elimination(self.possible_allergen_ingredients())
+    }
+}
+
+fn foods_from_strings(food_strings: &[String]) -> anyhow::Result<Vec<Food>> {
+    food_strings.iter().map(|s| s.parse()).collect()
+}
+
+fn dangerous_ingredient_list(foods: Vec<Food>) -> anyhow::Result<String> {
+    let mut allergen_ingredients: Vec<(Allergen, Ingredient)> = AllergenAssessor::new(foods)
+        .identify_allergen_ingredients()?
+        .into_iter()
+        .collect();
+    allergen_ingredients.sort();
+
+    Ok(allergen_ingredients
+        .into_iter()
+        .map(|(_allergen, ingredient)| ingredient.0)
+        .collect::<Vec<String>>()
+        .join(","))
+}
+
+pub fn count_appearances_of_allergen_free_ingredients(
+    food_strings: Vec<String>,
+) -> anyhow::Result<usize> {
+    Ok(AllergenAssessor::new(foods_from_strings(&food_strings)?)
+        .count_appearances_of_allergen_free_ingredients())
+}
+
+pub fn canonical_dangerous_ingredient_list(food_strings: Vec<String>) -> anyhow::Result<String> {
+    dangerous_ingredient_list(foods_from_strings(&food_strings)?)
+}
+
+pub struct Day21Solver;
+
+impl Solver for Day21Solver {
+    type Input = Vec<Food>;
+    type Answer = Answer;
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        foods_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => Ok(AllergenAssessor::new(input)
+                .count_appearances_of_allergen_free_ingredients()
+                .into()),
+            ChallengePart::Two => Ok(dangerous_ingredient_list(input)?.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn food_strings() -> Vec<String> {
+        [
+            "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)",
+            "trh fvjkl sbzzf mxmxvkd (contains dairy)",
+            "sqjhc fvjkl (contains soy)",
+            "sqjhc mxmxvkd sbzzf (contains fish)",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    #[test]
+    fn counts_appearances_of_allergen_free_ingredients() {
+        assert_that(&count_appearances_of_allergen_free_ingredients(food_strings()).unwrap())
+            .is_equal_to(5);
+    }
+
+    #[test]
+    fn gets_canonical_dangerous_ingredient_list() {
+        assert_that(&canonical_dangerous_ingredient_list(food_strings()).unwrap())
+            .is_equal_to("mxmxvkd,sqjhc,fvjkl".to_string());
+    }
+
+    #[test]
+    fn parses_food_without_allergens() {
+        let food: Food = "mxmxvkd kfcds".parse().unwrap();
+
+        assert_that(&food.ingredients().len()).is_equal_to(2);
+        assert_that(food.allergens()).is_empty();
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
+
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(test, derive(Debug))]
+enum Player {
+    One,
+    Two,
+}
+
+impl Display for Player {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Player::One => write!(f, "Player 1"),
+            Player::Two => write!(f, "Player 2"),
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Deck {
+    cards: VecDeque<u32>,
+}
+
+impl Deck {
+    fn new(cards: VecDeque<u32>) -> Self {
+        Deck { cards }
+    }
+
+    fn draw(&mut self) -> Option<u32> {
+        self.cards.pop_front()
+    }
+
+    fn place_at_bottom(&mut self, winning_card: u32, losing_card: u32) {
+        self.cards.push_back(winning_card);
+        self.cards.push_back(losing_card);
+    }
+
+    fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    fn copy_of_top(&self, count: usize) -> Self {
+        Deck::new(self.cards.iter().take(count).copied().collect())
+    }
+
+    fn score(&self) -> u64 {
+        self.cards
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(index, card)| (index as u64 + 1) * *card as u64)
+            .sum()
+    }
+}
+
+impl Display for Deck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cards: Vec<String> = self.cards.iter().map(ToString::to_string).collect();
+        write!(f, "{}", cards.join(", "))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CombatVariant {
+    Standard,
+    Recursive,
+}
+
+struct CombatGame {
+    variant: CombatVariant,
+    log: Option<Vec<String>>,
+    games_played: usize,
+}
+
+impl CombatGame {
+    fn new(variant: CombatVariant, record_log: bool) -> Self {
+        CombatGame {
+            variant,
+            log: if record_log { Some(vec![]) } else { None },
+            games_played: 0,
+        }
+    }
+
+    fn record(&mut self, entry: impl FnOnce() -> String) {
+        if let Some(log) = self.log.as_mut() {
+            log.push(entry());
+        }
+    }
+
+    fn play(&mut self, mut deck_1: Deck, mut deck_2: Deck) -> anyhow::Result<(Player, Deck)> {
+        self.games_played += 1;
+        let game = self.games_played;
+        let mut seen_states: HashSet<(Deck, Deck)> = HashSet::new();
+        let mut round = 0;
+
+        self.record(|| format!("=== Game {} ===", game));
+
+        while !deck_1.is_empty() && !deck_2.is_empty() {
+            if !seen_states.insert((deck_1.clone(), deck_2.clone())) {
+                if self.variant == CombatVariant::Standard {
+                    return Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+                        format!(
+                            "Game {} repeats its deck state after round {} and never ends",
+                            game, round
+                        ),
+                    )));
+                }
+
+                self.record(|| format!("Repeated deck state ends game {}", game));
+                return Ok((Player::One, deck_1));
+            }
+
+            round += 1;
+            self.record(|| format!("-- Round {} (Game {}) --", round, game));
+            self.record(|| format!("Player 1's deck: {}", deck_1));
+            self.record(|| format!("Player 2's deck: {}", deck_2));
+
+            let card_1 = deck_1.draw().unwrap();
+            let card_2 = deck_2.draw().unwrap();
+            self.record(|| format!("Player 1 plays: {}", card_1));
+            self.record(|| format!("Player 2 plays: {}", card_2));
+
+            let round_winner = if self.variant == CombatVariant::Recursive
+                && deck_1.len() >= card_1 as usize
+                && deck_2.len() >= card_2 as usize
+            {
+                self.record(|| "Playing a sub-game to determine the winner...".to_string());
+                let (sub_game_winner, _deck) = self.play(
+                    deck_1.copy_of_top(card_1 as usize),
+                    deck_2.copy_of_top(card_2 as usize),
+                )?;
+                self.record(|| format!("...anyway, back to game {}.", game));
+                sub_game_winner
+            } else if card_1 > card_2 {
+                Player::One
+            } else {
+                Player::Two
+            };
+
+            self.record(|| format!("{} wins round {} of game {}!", round_winner, round, game));
+            match round_winner {
+                Player::One => deck_1.place_at_bottom(card_1, card_2),
+                Player::Two => deck_2.place_at_bottom(card_2, card_1),
+            }
+        }
+
+        let (winner, winning_deck) = if deck_2.is_empty() {
+            (Player::One, deck_1)
+        } else {
+            (Player::Two, deck_2)
+        };
+        self.record(|| format!("The winner of game {} is {}!", game, winner));
+
+        Ok((winner, winning_deck))
+    }
+
+    fn into_log(self) -> Vec<String> {
+        self.log.unwrap_or_default()
+    }
+}
+
+fn deck_from_record(deck_record: &str) -> anyhow::Result<Deck> {
+    let lines: Vec<&str> = deck_record.lines().collect();
+    if lines.is_empty() || !lines[0].starts_with("Player") {
+        return Err(anyhow::Error::msg("Could not find player deck heading"));
+    }
+
+    let cards = lines[1..]
+        .iter()
+        .map(|line| line.parse())
+        .collect::<Result<VecDeque<u32>, _>>()?;
+
+    Ok(Deck::new(cards))
+}
+
+fn decks_from_records(deck_records: Vec<String>) -> anyhow::Result<(Deck, Deck)> {
+    let decks = deck_records
+        .iter()
+        .map(|deck_record| deck_from_record(deck_record))
+        .collect::<anyhow::Result<Vec<Deck>>>()?;
+
+    match decks.as_slice() {
+        [deck_1, deck_2] => Ok((deck_1.clone(), deck_2.clone())),
+        _ => Err(anyhow::Error::msg(format!(
+            "Expected 2 player decks but found {}",
+            decks.len()
+        ))),
+    }
+}
+
+fn play_with_log(
+    deck_1: Deck,
+    deck_2: Deck,
+    variant: CombatVariant,
+) -> anyhow::Result<(u64, Vec<String>)> {
+    let mut game = CombatGame::new(variant, true);
+    let (_winner, winning_deck) = game.play(deck_1, deck_2)?;
+
+    Ok((winning_deck.score(), game.into_log()))
+}
+
+pub fn play_crab_combat_with_log(
+    deck_records: Vec<String>,
+    variant: CombatVariant,
+) -> anyhow::Result<(u64, Vec<String>)> {
+    let (deck_1, deck_2) = decks_from_records(deck_records)?;
+
+    play_with_log(deck_1, deck_2, variant)
+}
+
+fn winning_player_score(deck_1: Deck, deck_2: Deck, variant: CombatVariant) -> anyhow::Result<u64> {
+    let (_winner, winning_deck) = CombatGame::new(variant, false).play(deck_1, deck_2)?;
+
+    Ok(winning_deck.score())
+}
+
+pub fn winning_player_score_of_combat(deck_records: Vec<String>) -> anyhow::Result<u64> {
+    let (deck_1, deck_2) = decks_from_records(deck_records)?;
+
+    winning_player_score(deck_1, deck_2, CombatVariant::Standard)
+}
+
+pub fn winning_player_score_of_recursive_combat(deck_records: Vec<String>) -> anyhow::Result<u64> {
+    let (deck_1, deck_2) = decks_from_records(deck_records)?;
+
+    winning_player_score(deck_1, deck_2, CombatVariant::Recursive)
+}
+
+fn combat_variant(part: ChallengePart) -> CombatVariant {
+    match part {
+        ChallengePart::One => CombatVariant::Standard,
+        ChallengePart::Two => CombatVariant::Recursive,
+    }
+}
+
+pub struct Day22Solver;
+
+impl Solver for Day22Solver {
+    type Input = (Deck, Deck);
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        decks_from_records(input.records())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        let (deck_1, deck_2) = input;
+
+        winning_player_score(deck_1, deck_2, combat_variant(part))
+    }
+
+    fn renders(&self) -> bool {
+        true
+    }
+
+    fn render(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<String> {
+        let (deck_1, deck_2) = input;
+        let (_score, log) = play_with_log(deck_1, deck_2, combat_variant(part))?;
+
+        Ok(log.iter().map(|entry| format!("{}\n", entry)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn deck_records() -> Vec<String> {
+        ["Player 1:\n9\n2\n6\n3\n1", "Player 2:\n5\n8\n4\n7\n10"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn gets_winning_player_score_of_combat() {
+        assert_that(&winning_player_score_of_combat(deck_records()).unwrap()).is_equal_to(306);
+    }
+
+    #[test]
+    fn gets_winning_player_score_of_recursive_combat() {
+        assert_that(&winning_player_score_of_recursive_combat(deck_records()).unwrap())
+            .is_equal_to(291);
+    }
+
+    #[test]
+    fn ends_recursive_combat_on_repeated_deck_state() {
+        let deck_records = ["Player 1:\n43\n19", "Player 2:\n2\n29\n14"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_that(&winning_player_score_of_recursive_combat(deck_records).unwrap())
+            .is_equal_to(105);
+    }
+
+    #[test]
+    fn fails_combat_on_repeated_deck_state() {
+        let deck_records = ["Player 1:\n43\n19", "Player 2:\n2\n29\n14"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_that(&winning_player_score_of_combat(deck_records).is_err()).is_true();
+    }
+
+    #[test]
+    fn logs_rounds_of_combat() {
+        let (score, log) =
+            play_crab_combat_with_log(deck_records(), CombatVariant::Standard).unwrap();
+
+        assert_that(&score).is_equal_to(306);
+        assert_that(&log.first().unwrap().as_str()).is_equal_to("=== Game 1 ===");
+        assert_that(&log.get(1).unwrap().as_str()).is_equal_to("-- Round 1 (Game 1) --");
+        assert_that(&log.last().unwrap().as_str()).is_equal_to("The winner of game 1 is Player 2!");
+        assert_that(
+            &log.iter()
+                .filter(|entry| entry.starts_with("-- Round"))
+                .count(),
+        )
+        .is_equal_to(29);
+    }
+}
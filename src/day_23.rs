@@ -0,0 +1,202 @@
+use crate::answer::Answer;
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+struct CupRing {
+    successors: Vec<usize>,
+    current_cup: usize,
+}
+
+impl CupRing {
+    fn new(starting_labels: &[usize], cup_count: usize) -> anyhow::Result<Self> {
+        if cup_count < 5 {
+            return Err(anyhow::Error::msg("At least five cups are required"));
+        }
+        if cup_count < starting_labels.len() {
+            return Err(anyhow::Error::msg(format!(
+                "Cannot fit {} labelled cups into {} cups",
+                starting_labels.len(),
+                cup_count
+            )));
+        }
+
+        let mut seen = vec![false; starting_labels.len() + 1];
+        for label in starting_labels {
+            if *label == 0 || *label > starting_labels.len() || seen[*label] {
+                return Err(anyhow::Error::msg(format!(
+                    "Cup labels must be a permutation of 1 to {}",
+                    starting_labels.len()
+                )));
+            }
+            seen[*label] = true;
+        }
+
+        let labels: Vec<usize> = starting_labels
+            .iter()
+            .copied()
+            .chain((starting_labels.len() + 1)..=cup_count)
+            .collect();
+
+        let mut successors = vec![0; cup_count + 1];
+        labels
+            .iter()
+            .zip(labels.iter().cycle().skip(1))
+            .for_each(|(cup, next_cup)| successors[*cup] = *next_cup);
+
+        Ok(CupRing {
+            successors,
+            current_cup: labels[0],
+        })
+    }
+
+    fn cup_count(&self) -> usize {
+        self.successors.len() - 1
+    }
+
+    fn cup_after(&self, cup: usize) -> usize {
+        self.successors[cup]
+    }
+
+    fn destination_cup(&self, picked_up: [usize; 3]) -> usize {
+        let mut destination = self.current_cup;
+        loop {
+            destination = if destination == 1 {
+                self.cup_count()
+            } else {
+                destination - 1
+            };
+            if !picked_up.contains(&destination) {
+                return destination;
+            }
+        }
+    }
+
+    fn make_move(&mut self) {
+        let first = self.cup_after(self.current_cup);
+        let second = self.cup_after(first);
+        let third = self.cup_after(second);
+
+        self.successors[self.current_cup] = self.cup_after(third);
+
+        let destination = self.destination_cup([first, second, third]);
+        self.successors[third] = self.cup_after(destination);
+        self.successors[destination] = first;
+
+        self.current_cup = self.cup_after(self.current_cup);
+    }
+
+    fn make_moves(&mut self, moves: usize) {
+        (0..moves).for_each(|_move| self.make_move());
+    }
+
+    fn labels_after_cup_1(&self) -> String {
+        let mut labels = String::new();
+        let mut cup = self.cup_after(1);
+        while cup != 1 {
+            labels.push_str(&cup.to_string());
+            cup = self.cup_after(cup);
+        }
+        labels
+    }
+}
+
+fn cup_labels_from_string(s: &str) -> anyhow::Result<Vec<usize>> {
+    s.trim()
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or_else(|| anyhow::Error::msg(format!("Could not parse cup label '{}'", c)))
+        })
+        .collect()
+}
+
+fn labels_after_crab_moves(cup_labels: &[usize], moves: usize) -> anyhow::Result<String> {
+    let mut cup_ring = CupRing::new(cup_labels, cup_labels.len())?;
+
+    cup_ring.make_moves(moves);
+
+    Ok(cup_ring.labels_after_cup_1())
+}
+
+fn product_of_cups_after_cup_1(cup_labels: &[usize]) -> anyhow::Result<u64> {
+    let mut cup_ring = CupRing::new(cup_labels, 1_000_000)?;
+
+    cup_ring.make_moves(10_000_000);
+
+    let first = cup_ring.cup_after(1);
+    let second = cup_ring.cup_after(first);
+    Ok(first as u64 * second as u64)
+}
+
+pub fn cup_labels_after_crab_moves(
+    cup_labels_string: &str,
+    moves: usize,
+) -> anyhow::Result<String> {
+    labels_after_crab_moves(&cup_labels_from_string(cup_labels_string)?, moves)
+}
+
+pub fn product_of_cups_after_cup_1_in_million_cup_game(
+    cup_labels_string: &str,
+) -> anyhow::Result<u64> {
+    product_of_cups_after_cup_1(&cup_labels_from_string(cup_labels_string)?)
+}
+
+pub struct Day23Solver;
+
+impl Solver for Day23Solver {
+    type Input = Vec<usize>;
+    type Answer = Answer;
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        let cup_labels_string = input
+            .lines()
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::Error::msg("Input is empty"))?;
+
+        cup_labels_from_string(&cup_labels_string)
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => Ok(labels_after_crab_moves(&input, 100)?.into()),
+            ChallengePart::Two => Ok(product_of_cups_after_cup_1(&input)?.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn gets_cup_labels_after_10_crab_moves() {
+        assert_that(&cup_labels_after_crab_moves("389125467", 10).unwrap())
+            .is_equal_to("92658374".to_string());
+    }
+
+    #[test]
+    fn gets_cup_labels_after_100_crab_moves() {
+        assert_that(&cup_labels_after_crab_moves("389125467", 100).unwrap())
+            .is_equal_to("67384529".to_string());
+    }
+
+    #[test]
+    fn gets_product_of_cups_after_cup_1_in_million_cup_game() {
+        assert_that(&product_of_cups_after_cup_1_in_million_cup_game("389125467").unwrap())
+            .is_equal_to(149245887792);
+    }
+
+    #[test]
+    fn fails_to_play_with_repeated_cup_labels() {
+        assert_that(&cup_labels_after_crab_moves("3891254677", 10)).is_err();
+    }
+}
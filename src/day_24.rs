@@ -0,0 +1,280 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Add;
+
+use crate::challenge::ChallengePart;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(test, derive(Debug))]
+struct HexCoordinate {
+    q: i64,
+    r: i64,
+}
+
+impl HexCoordinate {
+    fn new(q: i64, r: i64) -> Self {
+        HexCoordinate { q, r }
+    }
+
+    fn origin() -> Self {
+        HexCoordinate::new(0, 0)
+    }
+
+    fn neighbours(&self) -> Vec<Self> {
+        HexDirection::all()
+            .iter()
+            .map(|direction| *self + direction.offset())
+            .collect()
+    }
+}
+
+impl Add for HexCoordinate {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        HexCoordinate::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    fn all() -> [Self; 6] {
+        [
+            HexDirection::East,
+            HexDirection::SouthEast,
+            HexDirection::SouthWest,
+            HexDirection::West,
+            HexDirection::NorthWest,
+            HexDirection::NorthEast,
+        ]
+    }
+
+    fn offset(&self) -> HexCoordinate {
+        match self {
+            HexDirection::East => HexCoordinate::new(1, 0),
+            HexDirection::SouthEast => HexCoordinate::new(0, 1),
+            HexDirection::SouthWest => HexCoordinate::new(-1, 1),
+            HexDirection::West => HexCoordinate::new(-1, 0),
+            HexDirection::NorthWest => HexCoordinate::new(0, -1),
+            HexDirection::NorthEast => HexCoordinate::new(1, -1),
+        }
+    }
+}
+
+fn hex_directions_from_path_string(s: &str) -> anyhow::Result<Vec<HexDirection>> {
+    let mut directions = vec![];
+    let mut characters = s.chars();
+
+    while let Some(c) = characters.next() {
+        let direction = match c {
+            'e' => HexDirection::East,
+            'w' => HexDirection::West,
+            's' | 'n' => match (c, characters.next()) {
+                ('s', Some('e')) => HexDirection::SouthEast,
+                ('s', Some('w')) => HexDirection::SouthWest,
+                ('n', Some('w')) => HexDirection::NorthWest,
+                ('n', Some('e')) => HexDirection::NorthEast,
+                _ => {
+                    return Err(anyhow::Error::msg(format!(
+                        "Could not parse hex direction in path '{}'",
+                        s
+                    )))
+                }
+            },
+            _ => {
+                return Err(anyhow::Error::msg(format!(
+                    "Could not parse hex direction '{}' in path '{}'",
+                    c, s
+                )))
+            }
+        };
+        directions.push(direction);
+    }
+
+    Ok(directions)
+}
+
+fn tile_at_end_of_path(directions: &[HexDirection]) -> HexCoordinate {
+    directions
+        .iter()
+        .fold(HexCoordinate::origin(), |tile, direction| {
+            tile + direction.offset()
+        })
+}
+
+struct LobbyFloor {
+    black_tiles: HashSet<HexCoordinate>,
+}
+
+impl LobbyFloor {
+    fn new() -> Self {
+        LobbyFloor {
+            black_tiles: HashSet::new(),
+        }
+    }
+
+    fn flip(&mut self, tile: HexCoordinate) {
+        if !self.black_tiles.remove(&tile) {
+            self.black_tiles.insert(tile);
+        }
+    }
+
+    fn count_black_neighbours(&self) -> HashMap<HexCoordinate, usize> {
+        let mut black_neighbour_counts: HashMap<HexCoordinate, usize> = HashMap::new();
+
+        self.black_tiles.iter().for_each(|tile| {
+            tile.neighbours().into_iter().for_each(|neighbour| {
+                *black_neighbour_counts.entry(neighbour).or_insert(0) += 1;
+            })
+        });
+
+        black_neighbour_counts
+    }
+
+    fn pass_day(&mut self) {
+        self.black_tiles = self
+            .count_black_neighbours()
+            .into_iter()
+            .filter(|(tile, black_neighbours)| {
+                if self.black_tiles.contains(tile) {
+                    *black_neighbours == 1 || *black_neighbours == 2
+                } else {
+                    *black_neighbours == 2
+                }
+            })
+            .map(|(tile, _black_neighbours)| tile)
+            .collect();
+    }
+
+    fn black_tile_count(&self) -> usize {
+        self.black_tiles.len()
+    }
+}
+
+fn paths_from_strings(path_strings: &[String]) -> anyhow::Result<Vec<Vec<HexDirection>>> {
+    path_strings
+        .iter()
+        .map(|s| hex_directions_from_path_string(s))
+        .collect()
+}
+
+fn lobby_floor_from_paths(paths: &[Vec<HexDirection>]) -> LobbyFloor {
+    let mut lobby_floor = LobbyFloor::new();
+    paths
+        .iter()
+        .for_each(|path| lobby_floor.flip(tile_at_end_of_path(path)));
+
+    lobby_floor
+}
+
+fn black_tiles_after_days(paths: &[Vec<HexDirection>], days: usize) -> usize {
+    let mut lobby_floor = lobby_floor_from_paths(paths);
+
+    (0..days).for_each(|_day| lobby_floor.pass_day());
+
+    lobby_floor.black_tile_count()
+}
+
+pub fn count_black_tiles_after_flipping(path_strings: Vec<String>) -> anyhow::Result<usize> {
+    Ok(lobby_floor_from_paths(&paths_from_strings(&path_strings)?).black_tile_count())
+}
+
+pub fn count_black_tiles_after_days(
+    path_strings: Vec<String>,
+    days: usize,
+) -> anyhow::Result<usize> {
+    Ok(black_tiles_after_days(
+        &paths_from_strings(&path_strings)?,
+        days,
+    ))
+}
+
+pub struct Day24Solver;
+
+impl Solver for Day24Solver {
+    type Input = Vec<Vec<HexDirection>>;
+    type Answer = usize;
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        paths_from_strings(&input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => Ok(lobby_floor_from_paths(&input).black_tile_count()),
+            ChallengePart::Two => Ok(black_tiles_after_days(&input, 100)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn path_strings() -> Vec<String> {
+        [
+            "sesenwnenenewseeswwswswwnenewsewsw",
+            "neeenesenwnwwswnenewnwwsewnenwseswesw",
+            "seswneswswsenwwnwse",
+            "nwnwneseeswswnenewneswwnewseswneseene",
+            "swweswneswnenwsewnwneneseenw",
+            "eesenwseswswnenwswnwnwsewwnwsene",
+            "sewnenenenesenwsewnenwwwse",
+            "wenwwweseeeweswwwnwwe",
+            "wsweesenenewnwwnwsenewsenwwsesesenwne",
+            "neeswseenwwswnwswswnw",
+            "nenwswwsewswnenenewsenwsenwnesesenew",
+            "enewnwewneswsewnwswenweswnenwsenwsw",
+            "sweneswneswneneenwnewenewwneswswnese",
+            "swwesenesewenwneswnwwneseswwne",
+            "enesenwswwswneneswsenwnewswseenwsese",
+            "wnwnesenesenenwwnenwsewesewsesesew",
+            "nenewswnwewswnenesenwnesewesw",
+            "eneswnwswnwsenenwnwnwwseeswneewsenese",
+            "neswnwewnwnwseenwseesewsenwsweewe",
+            "wseweeenwnesenwwwswnew",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    #[test]
+    fn counts_black_tiles_after_flipping() {
+        assert_that(&count_black_tiles_after_flipping(path_strings()).unwrap()).is_equal_to(10);
+    }
+
+    #[test]
+    fn counts_black_tiles_after_100_days() {
+        assert_that(&count_black_tiles_after_days(path_strings(), 100).unwrap()).is_equal_to(2208);
+    }
+
+    #[test]
+    fn follows_path_back_to_reference_tile() {
+        let directions = hex_directions_from_path_string("nwwswee").unwrap();
+
+        assert_that(&tile_at_end_of_path(&directions)).is_equal_to(HexCoordinate::origin());
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_hex_direction() {
+        assert_that(&hex_directions_from_path_string("esn")).is_err();
+    }
+}
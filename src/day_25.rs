@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use crate::challenge::ChallengePart;
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+use crate::solver::Solver;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT_NUMBER: u64 = 7;
+
+fn transform(subject_number: u64, loop_size: u64) -> u64 {
+    let mut result = 1;
+    let mut base = subject_number % MODULUS;
+    let mut exponent = loop_size;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % MODULUS;
+        }
+        base = base * base % MODULUS;
+        exponent >>= 1;
+    }
+
+    result
+}
+
+fn find_loop_size(public_key: u64) -> anyhow::Result<u64> {
+    if public_key == 0 || public_key >= MODULUS {
+        return Err(anyhow::Error::msg(format!(
+            "Public key {} is not a valid key",
+            public_key
+        )));
+    }
+
+    let step_count = (MODULUS as f64).sqrt().ceil() as u64;
+
+    let baby_steps: HashMap<u64, u64> = (0..step_count)
+        .scan(1, |value, step| {
+            let baby_step = (*value, step);
+            *value = *value * SUBJECT_NUMBER % MODULUS;
+            Some(baby_step)
+        })
+        .collect();
+
+    let giant_step_factor = transform(SUBJECT_NUMBER, MODULUS - 1 - step_count);
+    let mut value = public_key;
+
+    for giant_step in 0..step_count {
+        if let Some(baby_step) = baby_steps.get(&value) {
+            return Ok(giant_step * step_count + baby_step);
+        }
+        value = value * giant_step_factor % MODULUS;
+    }
+
+    Err(anyhow::Error::new(ChallengeError::NoSolutionFound(
+        format!("Could not find loop size for public key {}", public_key),
+    )))
+}
+
+fn public_keys_from_strings(public_key_strings: Vec<String>) -> anyhow::Result<(u64, u64)> {
+    let public_keys = public_key_strings
+        .iter()
+        .map(|s| s.parse())
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    match public_keys.as_slice() {
+        [card_public_key, door_public_key] => Ok((*card_public_key, *door_public_key)),
+        _ => Err(anyhow::Error::msg(format!(
+            "Expected 2 public keys but found {}",
+            public_keys.len()
+        ))),
+    }
+}
+
+fn encryption_key(card_public_key: u64, door_public_key: u64) -> anyhow::Result<u64> {
+    let card_loop_size = find_loop_size(card_public_key)?;
+
+    Ok(transform(door_public_key, card_loop_size))
+}
+
+pub fn encryption_key_from_public_keys(public_key_strings: Vec<String>) -> anyhow::Result<u64> {
+    let (card_public_key, door_public_key) = public_keys_from_strings(public_key_strings)?;
+
+    encryption_key(card_public_key, door_public_key)
+}
+
+pub struct Day25Solver;
+
+impl Solver for Day25Solver {
+    type Input = (u64, u64);
+    type Answer = u64;
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parts(&self) -> &[ChallengePart] {
+        &[ChallengePart::One]
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input> {
+        public_keys_from_strings(input.lines())
+    }
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer> {
+        match part {
+            ChallengePart::One => {
+                let (card_public_key, door_public_key) = input;
+
+                encryption_key(card_public_key, door_public_key)
+            }
+            ChallengePart::Two => Err(anyhow::Error::new(ChallengeError::NotImplemented(
+                "Day 25 has no part 2".to_string(),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn finds_card_loop_size() {
+        assert_that(&find_loop_size(5764801).unwrap()).is_equal_to(8);
+    }
+
+    #[test]
+    fn finds_door_loop_size() {
+        assert_that(&find_loop_size(17807724).unwrap()).is_equal_to(11);
+    }
+
+    #[test]
+    fn gets_encryption_key_from_public_keys() {
+        let public_key_strings = ["5764801", "17807724"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_that(&encryption_key_from_public_keys(public_key_strings).unwrap())
+            .is_equal_to(14897079);
+    }
+
+    #[test]
+    fn fails_to_find_loop_size_of_invalid_public_key() {
+        assert_that(&find_loop_size(MODULUS)).is_err();
+    }
+}
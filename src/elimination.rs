@@ -0,0 +1,65 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::error::ChallengeError;
+
+pub(crate) fn resolve_by_elimination<K, V>(
+    mut candidates: HashMap<K, HashSet<V>>,
+) -> anyhow::Result<HashMap<K, V>>
+where
+    K: Hash + Eq + Clone,
+    V: Hash + Eq + Clone,
+{
+    let mut resolved: HashMap<K, V> = HashMap::new();
+
+    while !candidates.is_empty() {
+        let (key, value) = candidates
+            .iter()
+            .find(|(_key, values)| values.len() == 1)
+            .map(|(key, values)| (key.clone(), values.iter().next().unwrap().clone()))
+            .ok_or_else(|| {
+                anyhow::Error::new(ChallengeError::NoSolutionFound(
+                    "Could not resolve candidates by elimination".to_string(),
+                ))
+            })?;
+
+        candidates.remove(&key);
+        candidates.values_mut().for_each(|values| {
+            values.remove(&value);
+        });
+        resolved.insert(key, value);
+    }
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::FromIterator;
+
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn resolves_candidates_by_elimination() {
+        let candidates: HashMap<u8, HashSet<char>> = HashMap::from_iter(vec![
+            (0, HashSet::from_iter(vec!['a', 'b', 'c'])),
+            (1, HashSet::from_iter(vec!['a'])),
+            (2, HashSet::from_iter(vec!['a', 'b'])),
+        ]);
+
+        assert_that(&resolve_by_elimination(candidates).unwrap())
+            .is_equal_to(HashMap::from_iter(vec![(0, 'c'), (1, 'a'), (2, 'b')]));
+    }
+
+    #[test]
+    fn fails_to_resolve_ambiguous_candidates() {
+        let candidates: HashMap<u8, HashSet<char>> = HashMap::from_iter(vec![
+            (0, HashSet::from_iter(vec!['a', 'b'])),
+            (1, HashSet::from_iter(vec!['a', 'b'])),
+        ]);
+
+        assert_that(&resolve_by_elimination(candidates)).is_err();
+    }
+}
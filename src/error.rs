@@ -0,0 +1,20 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum ChallengeError {
+    NotImplemented(String),
+    NoSolutionFound(String),
+    CheckFailed(String),
+}
+
+impl Display for ChallengeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChallengeError::NotImplemented(message) => write!(f, "{}", message),
+            ChallengeError::NoSolutionFound(message) => write!(f, "{}", message),
+            ChallengeError::CheckFailed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ChallengeError {}
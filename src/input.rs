@@ -0,0 +1,67 @@
+pub struct ChallengeInput {
+    text: String,
+}
+
+impl ChallengeInput {
+    pub fn new(text: &str) -> Self {
+        ChallengeInput {
+            text: text.replace("\r\n", "\n"),
+        }
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self.text.lines().map(ToString::to_string).collect();
+        while lines.last().is_some_and(|line| is_blank(line)) {
+            lines.pop();
+        }
+        lines
+    }
+
+    pub fn records(&self) -> Vec<String> {
+        self.lines()
+            .split(|line| is_blank(line))
+            .filter(|record_lines| !record_lines.is_empty())
+            .map(|record_lines| record_lines.join("\n"))
+            .collect()
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn normalises_crlf_line_endings() {
+        let input = ChallengeInput::new("abc\r\n\r\ndef\r\n");
+
+        assert_that(&input.raw()).is_equal_to("abc\n\ndef\n");
+    }
+
+    #[test]
+    fn splits_lines_without_trailing_blank_lines() {
+        let input = ChallengeInput::new("abc\n  de f\n\n \n");
+
+        assert_that(&input.lines()).is_equal_to(vec!["abc".to_string(), "  de f".to_string()]);
+    }
+
+    #[test]
+    fn splits_records_on_blank_lines() {
+        let input = ChallengeInput::new("a\nb\n\nc\n  \t\n\nd\r\ne\r\n");
+
+        assert_that(&input.records()).is_equal_to(vec![
+            "a\nb".to_string(),
+            "c".to_string(),
+            "d\ne".to_string(),
+        ]);
+    }
+}
@@ -1,286 +1,243 @@
 use std::fs;
-use std::num::ParseIntError;
-use std::path::PathBuf;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
 
+use anyhow::Context;
 use structopt::StructOpt;
 
 use advent_of_code_2020::answer::Answer;
-use advent_of_code_2020::challenge::{Challenge, ChallengePart};
-use advent_of_code_2020::day_01::{product_of_2020_sum_pair, product_of_2020_sum_triplet};
-use advent_of_code_2020::day_02::{
-    count_policies_satisfied_by_passwords, to_policy_and_password,
-    OccurrenceRestrictedPasswordPolicy, Password, PositionallyRestrictedPasswordPolicy,
+use advent_of_code_2020::challenge::Challenge;
+use advent_of_code_2020::error::ChallengeError;
+use advent_of_code_2020::input::ChallengeInput;
+use advent_of_code_2020::provider::{HttpInputFetcher, InputFetcher, InputProvider};
+use advent_of_code_2020::solver::SolverRegistry;
+use advent_of_code_2020::verification::ExpectedAnswers;
+
+use crate::cli::{Opt, OutputFormat};
+use crate::report::{
+    check_report, json_lines, plain_answers, summary_table, timing_table, ChallengeOutcome,
+    StageTimings,
 };
-use advent_of_code_2020::day_03::MovementDirection::{Down, Right};
-use advent_of_code_2020::day_03::{
-    count_encountered_trees_for_movement_sequence,
-    product_of_tree_encounters_for_movement_sequences,
-};
-use advent_of_code_2020::day_04::{
-    count_valid_relaxed_validation_passports_in_text,
-    count_valid_strict_validation_passports_in_text,
-};
-use advent_of_code_2020::day_05::{find_highest_seat_id_on_plane, find_my_empty_seat_id};
-use advent_of_code_2020::day_06::{
-    count_total_group_intersecting_positive_answers, count_total_group_unified_positive_answers,
-};
-use advent_of_code_2020::day_07::count_bags_that_eventually_contain;
-use advent_of_code_2020::day_08::{
-    get_accumulator_value_after_termination_of_fixed_instructions,
-    get_accumulator_value_before_repeated_instruction,
-};
-use advent_of_code_2020::day_09::{find_first_xmas_encoding_error, get_encryption_weakness};
-use advent_of_code_2020::day_10::product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in;
-use advent_of_code_2020::day_11::count_occupied_seats_after_occupancy_stabilisation;
-use advent_of_code_2020::day_12::{
-    get_manhattan_distance_to_directed_location,
-    get_manhattan_distance_to_directed_location_with_waypoint_navigation,
-};
-use advent_of_code_2020::day_13::get_product_of_id_of_earliest_bus_and_wait_time;
-use advent_of_code_2020::day_15::nth_spoken_number_in_recitation_game;
-use advent_of_code_2020::day_16::{
-    product_of_my_departure_field_values, ticket_scanning_error_rate_for_input_nearby_tickets,
-};
-
-use crate::cli::Opt;
 
 mod cli;
+mod report;
 
-fn main() {
-    let opt = Opt::from_args();
-    let challenge = opt.challenge();
-    let input_text_lines = read_input_file(opt.input()).unwrap();
-
-    execute_challenge(challenge, input_text_lines);
-}
-
-fn execute_challenge(challenge: Challenge, input_text_lines: Vec<String>) {
-    match challenge.day() {
-        1 => run_day_1(challenge.part(), input_text_lines).unwrap(),
-        2 => run_day_2(challenge.part(), input_text_lines).unwrap(),
-        3 => run_day_3(challenge.part(), input_text_lines).unwrap(),
-        4 => run_day_4(challenge.part(), input_text_lines).unwrap(),
-        5 => run_day_5(challenge.part(), input_text_lines).unwrap(),
-        6 => run_day_6(challenge.part(), input_text_lines).unwrap(),
-        7 => run_day_7(challenge.part(), input_text_lines).unwrap(),
-        8 => run_day_8(challenge.part(), input_text_lines).unwrap(),
-        9 => run_day_9(challenge.part(), input_text_lines).unwrap(),
-        10 => run_day_10(challenge.part(), input_text_lines).unwrap(),
-        11 => run_day_11(challenge.part(), input_text_lines).unwrap(),
-        12 => run_day_12(challenge.part(), input_text_lines).unwrap(),
-        13 => run_day_13(challenge.part(), input_text_lines).unwrap(),
-        15 => run_day_15(challenge.part(), input_text_lines).unwrap(),
-        16 => run_day_16(challenge.part(), input_text_lines).unwrap(),
-        _ => unimplemented!(),
-    }
-}
-
-fn run_day_1(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let numbers: Vec<u64> = input_text_lines
-        .iter()
-        .map(|s| s.as_str().parse())
-        .collect::<Result<Vec<u64>, ParseIntError>>()?;
-
-    let result = match part {
-        ChallengePart::One => product_of_2020_sum_pair(&*numbers).unwrap(),
-        ChallengePart::Two => product_of_2020_sum_triplet(&*numbers).unwrap(),
-    };
+const NOT_IMPLEMENTED_EXIT_CODE: i32 = 2;
+const INPUT_ERROR_EXIT_CODE: i32 = 3;
+const NO_SOLUTION_FOUND_EXIT_CODE: i32 = 4;
+const CHECK_FAILED_EXIT_CODE: i32 = 5;
+const STDIN_INPUT_PATH: &str = "-";
 
-    println!("{}", Answer::new(result));
-    Ok(())
+#[derive(Clone)]
+enum InputSource {
+    File(PathBuf),
+    Text(String),
+    Provider(u8),
 }
 
-fn run_day_2(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: usize = match part {
-        ChallengePart::One => count_policies_satisfied_by_passwords(
-            input_text_lines
-                .iter()
-                .map(to_policy_and_password)
-                .collect::<anyhow::Result<Vec<(OccurrenceRestrictedPasswordPolicy, Password)>>>()?,
-        ),
-        ChallengePart::Two => count_policies_satisfied_by_passwords(
-            input_text_lines
-                .iter()
-                .map(to_policy_and_password)
-                .collect::<anyhow::Result<Vec<(PositionallyRestrictedPasswordPolicy, Password)>>>(
-                )?,
-        ),
-    };
-
-    println!("{}", Answer::new(result));
-    Ok(())
-}
+fn main() -> anyhow::Result<()> {
+    let opt = Opt::from_args();
 
-fn run_day_3(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => count_encountered_trees_for_movement_sequence(
-            input_text_lines,
-            vec![Right, Right, Right, Down],
-        ),
-        ChallengePart::Two => product_of_tree_encounters_for_movement_sequences(
-            input_text_lines,
-            vec![
-                vec![Right, Down],
-                vec![Right, Right, Right, Down],
-                vec![Right, Right, Right, Right, Right, Down],
-                vec![Right, Right, Right, Right, Right, Right, Right, Down],
-                vec![Right, Down, Down],
-            ],
-        ),
-    };
+    if let Err(error) = run(opt) {
+        eprintln!("Error: {:#}", error);
+        process::exit(exit_code(&error));
+    }
 
-    println!("{}", Answer::new(result));
     Ok(())
 }
 
-fn run_day_4(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let passport_strings: Vec<String> = input_text_lines
-        .join("\n")
-        .split("\n\n")
-        .map(ToString::to_string)
-        .collect();
+fn run(opt: Opt) -> anyhow::Result<()> {
+    let registry = SolverRegistry::default();
+    let provider = input_provider(&opt);
+    let challenges = opt.challenges(&registry);
 
-    let result: usize = match part {
-        ChallengePart::One => count_valid_relaxed_validation_passports_in_text(passport_strings),
-        ChallengePart::Two => count_valid_strict_validation_passports_in_text(passport_strings),
+    let given_input = given_input_source(&opt)?;
+    if challenges.len() > 1 && given_input.is_some() {
+        return Err(anyhow::Error::msg(
+            "An input file or text can only be given for a single challenge",
+        ));
+    }
+    let input_source = |challenge: &Challenge| {
+        given_input
+            .clone()
+            .unwrap_or(InputSource::Provider(challenge.day()))
     };
 
-    println!("{}", Answer::new(result));
-    Ok(())
-}
+    if opt.render() {
+        let challenge = match challenges.as_slice() {
+            [challenge] => *challenge,
+            _ => {
+                return Err(anyhow::Error::msg(
+                    "Only a single challenge can be rendered",
+                ))
+            }
+        };
+        registry.ensure_renderable(challenge)?;
+        let input = read_input(&provider, input_source(&challenge))?;
+
+        print!("{}", registry.render(challenge, &input)?);
+        return Ok(());
+    }
 
-fn run_day_5(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result = match part {
-        ChallengePart::One => find_highest_seat_id_on_plane(input_text_lines)?,
-        ChallengePart::Two => find_my_empty_seat_id(input_text_lines)?,
+    let expected_answers = if opt.check() {
+        Some(ExpectedAnswers::from_file(&opt.answers())?)
+    } else {
+        None
     };
 
-    println!("{}", Answer::new(result));
-    Ok(())
-}
+    if let (OutputFormat::Text, [challenge], false, None) = (
+        opt.format(),
+        challenges.as_slice(),
+        opt.time(),
+        &expected_answers,
+    ) {
+        let (answer, _timings) =
+            execute_challenge(&registry, &provider, *challenge, input_source(challenge))?;
+
+        println!("Answer: {}", answer);
+        return Ok(());
+    }
 
-fn run_day_6(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let passport_strings: Vec<String> = input_text_lines
-        .join("\n")
-        .split("\n\n")
-        .map(ToString::to_string)
+    let outcomes: Vec<ChallengeOutcome> = challenges
+        .iter()
+        .map(|challenge| {
+            let mut outcome = run_challenge(
+                &registry,
+                &provider,
+                *challenge,
+                input_source(challenge),
+                opt.repeat(),
+            );
+            if let Some(answers) = &expected_answers {
+                outcome.check(answers);
+            }
+            outcome
+        })
         .collect();
 
-    let result: usize = match part {
-        ChallengePart::One => count_total_group_unified_positive_answers(passport_strings),
-        ChallengePart::Two => count_total_group_intersecting_positive_answers(passport_strings),
-    };
-
-    println!("{}", Answer::new(result));
-    Ok(())
-}
-
-fn run_day_7(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: usize = match part {
-        ChallengePart::One => count_bags_that_eventually_contain(input_text_lines, "shiny gold")?,
-        ChallengePart::Two => unimplemented!(),
-    };
-
-    println!("{}", Answer::new(result));
-    Ok(())
-}
-
-fn run_day_8(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: i64 = match part {
-        ChallengePart::One => get_accumulator_value_before_repeated_instruction(input_text_lines)?,
-        ChallengePart::Two => {
-            get_accumulator_value_after_termination_of_fixed_instructions(input_text_lines)?
+    match opt.format() {
+        OutputFormat::Text if opt.time() => {
+            print!("{}", timing_table(&outcomes, opt.repeat()));
+            print!("{}", check_report(&outcomes));
         }
-    };
-
-    println!("{}", Answer::new(result));
-    Ok(())
-}
+        OutputFormat::Text => {
+            print!("{}", summary_table(&outcomes));
+            print!("{}", check_report(&outcomes));
+        }
+        OutputFormat::Json => print!("{}", json_lines(&outcomes)),
+        OutputFormat::Plain => print!("{}", plain_answers(&outcomes)),
+    }
 
-fn run_day_9(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => find_first_xmas_encoding_error(input_text_lines, 25)?,
-        ChallengePart::Two => get_encryption_weakness(input_text_lines, 25)?,
-    };
+    let checked_count = outcomes.len();
+    let failed_check_count = outcomes
+        .iter()
+        .filter(|outcome| outcome.failed_check())
+        .count();
+    outcomes
+        .into_iter()
+        .try_for_each(|outcome| outcome.into_result().map(|_answer| ()))?;
+
+    if failed_check_count > 0 {
+        return Err(anyhow::Error::new(ChallengeError::CheckFailed(format!(
+            "{} of {} answers failed the check",
+            failed_check_count, checked_count
+        ))));
+    }
 
-    println!("{}", Answer::new(result));
     Ok(())
 }
 
-fn run_day_10(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => {
-            product_of_1_and_3_joltage_differences_using_every_adapter_and_built_in(
-                input_text_lines,
-            )?
-        }
-        ChallengePart::Two => unimplemented!(),
-    };
-
-    println!("{}", Answer::new(result));
-    Ok(())
+fn exit_code(error: &anyhow::Error) -> i32 {
+    match error.downcast_ref::<ChallengeError>() {
+        Some(ChallengeError::NotImplemented(_)) => NOT_IMPLEMENTED_EXIT_CODE,
+        Some(ChallengeError::NoSolutionFound(_)) => NO_SOLUTION_FOUND_EXIT_CODE,
+        Some(ChallengeError::CheckFailed(_)) => CHECK_FAILED_EXIT_CODE,
+        None => INPUT_ERROR_EXIT_CODE,
+    }
 }
 
-fn run_day_11(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: usize = match part {
-        ChallengePart::One => count_occupied_seats_after_occupancy_stabilisation(input_text_lines)?,
-        ChallengePart::Two => unimplemented!(),
-    };
+fn run_challenge(
+    registry: &SolverRegistry,
+    provider: &InputProvider,
+    challenge: Challenge,
+    input_source: InputSource,
+    repeat: usize,
+) -> ChallengeOutcome {
+    let mut timings = vec![];
+    let mut result = Err(anyhow::Error::msg("Challenge was not run"));
+
+    for _run in 0..repeat {
+        match execute_challenge(registry, provider, challenge, input_source.clone()) {
+            Ok((answer, run_timings)) => {
+                result = Ok(answer);
+                timings.push(run_timings);
+            }
+            Err(error) => {
+                result = Err(error);
+                break;
+            }
+        }
+    }
 
-    println!("{}", Answer::new(result));
-    Ok(())
+    ChallengeOutcome::new(challenge, result, timings)
 }
 
-fn run_day_12(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => get_manhattan_distance_to_directed_location(input_text_lines)?,
-        ChallengePart::Two => {
-            get_manhattan_distance_to_directed_location_with_waypoint_navigation(input_text_lines)?
-        }
-    };
+fn execute_challenge(
+    registry: &SolverRegistry,
+    provider: &InputProvider,
+    challenge: Challenge,
+    input_source: InputSource,
+) -> anyhow::Result<(Answer, StageTimings)> {
+    registry.ensure_implemented(challenge)?;
 
-    println!("{}", Answer::new(result));
-    Ok(())
-}
+    let read_start = Instant::now();
+    let input = read_input(provider, input_source)?;
+    let reading = read_start.elapsed();
 
-fn run_day_13(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => get_product_of_id_of_earliest_bus_and_wait_time(input_text_lines)?,
-        ChallengePart::Two => unimplemented!(),
-    };
+    let (answer, solve_timings) = registry.solve_with_timings(challenge, &input)?;
 
-    println!("{}", Answer::new(result));
-    Ok(())
+    Ok((
+        answer,
+        StageTimings::new(reading, solve_timings.parsing(), solve_timings.solving()),
+    ))
 }
 
-fn run_day_15(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => {
-            nth_spoken_number_in_recitation_game(2020, input_text_lines.first().unwrap().clone())?
+fn given_input_source(opt: &Opt) -> anyhow::Result<Option<InputSource>> {
+    match (opt.input_text(), opt.input()) {
+        (Some(input_text), _) => Ok(Some(InputSource::Text(input_text))),
+        (None, Some(path)) if path == Path::new(STDIN_INPUT_PATH) => {
+            let mut input_text = String::new();
+            io::stdin()
+                .read_to_string(&mut input_text)
+                .context("Could not read input from stdin")?;
+            Ok(Some(InputSource::Text(input_text)))
         }
-        ChallengePart::Two => nth_spoken_number_in_recitation_game(
-            30000000,
-            input_text_lines.first().unwrap().clone(),
-        )?,
-    };
-
-    println!("{}", Answer::new(result));
-    Ok(())
+        (None, Some(path)) => Ok(Some(InputSource::File(path))),
+        (None, None) => Ok(None),
+    }
 }
 
-fn run_day_16(part: ChallengePart, input_text_lines: Vec<String>) -> anyhow::Result<()> {
-    let result: u64 = match part {
-        ChallengePart::One => {
-            ticket_scanning_error_rate_for_input_nearby_tickets(input_text_lines)?
-        }
-        ChallengePart::Two => product_of_my_departure_field_values(input_text_lines)?,
-    };
+fn input_provider(opt: &Opt) -> InputProvider {
+    let fetcher = opt.session_token().map(|session_token| {
+        Box::new(HttpInputFetcher::with_session_token(&session_token)) as Box<dyn InputFetcher>
+    });
 
-    println!("{}", Answer::new(result));
-    Ok(())
+    InputProvider::new(opt.input_dir(), fetcher)
 }
 
-fn read_input_file(p: PathBuf) -> anyhow::Result<Vec<String>> {
-    let file_string = fs::read_to_string(p)?.trim().to_string();
-    Ok(file_string.lines().map(ToString::to_string).collect())
+fn read_input(
+    provider: &InputProvider,
+    input_source: InputSource,
+) -> anyhow::Result<ChallengeInput> {
+    match input_source {
+        InputSource::File(p) => {
+            let input_text = fs::read_to_string(&p)
+                .with_context(|| format!("Could not read input file {}", p.display()))?;
+            Ok(ChallengeInput::new(&input_text))
+        }
+        InputSource::Text(input_text) => Ok(ChallengeInput::new(&input_text)),
+        InputSource::Provider(day) => provider.input(day),
+    }
 }
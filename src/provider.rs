@@ -0,0 +1,270 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::input::ChallengeInput;
+
+const ADVENT_OF_CODE_URL: &str = "https://adventofcode.com/2020";
+
+pub trait InputFetcher {
+    fn fetch(&self, day: u8) -> anyhow::Result<String>;
+}
+
+pub struct HttpInputFetcher {
+    base_url: String,
+    session_token: String,
+}
+
+impl HttpInputFetcher {
+    pub fn new(base_url: &str, session_token: &str) -> Self {
+        HttpInputFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session_token: session_token.to_string(),
+        }
+    }
+
+    pub fn with_session_token(session_token: &str) -> Self {
+        Self::new(ADVENT_OF_CODE_URL, session_token)
+    }
+}
+
+impl InputFetcher for HttpInputFetcher {
+    fn fetch(&self, day: u8) -> anyhow::Result<String> {
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session_token))
+            .set(
+                "User-Agent",
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+            )
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, _response) => anyhow::Error::msg(format!(
+                    "Fetching input for day {} failed with status {}",
+                    day, status
+                )),
+                ureq::Error::Transport(transport) => anyhow::Error::msg(format!(
+                    "Could not fetch input for day {}: {}",
+                    day, transport
+                )),
+            })?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+pub struct DirectoryInputFetcher {
+    directory: PathBuf,
+}
+
+impl DirectoryInputFetcher {
+    pub fn new(directory: PathBuf) -> Self {
+        DirectoryInputFetcher { directory }
+    }
+}
+
+impl InputFetcher for DirectoryInputFetcher {
+    fn fetch(&self, day: u8) -> anyhow::Result<String> {
+        let path = day_input_path(&self.directory, day);
+
+        fs::read_to_string(&path)
+            .with_context(|| format!("Could not read input file {}", path.display()))
+    }
+}
+
+pub struct InputProvider {
+    cache_directory: PathBuf,
+    fetcher: Option<Box<dyn InputFetcher>>,
+}
+
+impl InputProvider {
+    pub fn new(cache_directory: PathBuf, fetcher: Option<Box<dyn InputFetcher>>) -> Self {
+        InputProvider {
+            cache_directory,
+            fetcher,
+        }
+    }
+
+    pub fn cached_input_path(&self, day: u8) -> PathBuf {
+        day_input_path(&self.cache_directory, day)
+    }
+
+    pub fn input(&self, day: u8) -> anyhow::Result<ChallengeInput> {
+        let path = self.cached_input_path(day);
+        if path.exists() {
+            let input_text = fs::read_to_string(&path)
+                .with_context(|| format!("Could not read input file {}", path.display()))?;
+            return Ok(ChallengeInput::new(&input_text));
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| {
+            anyhow::Error::msg(format!(
+                "Could not find input file {} and no input fetcher is configured",
+                path.display()
+            ))
+        })?;
+        let input_text = fetcher.fetch(day)?;
+
+        fs::create_dir_all(&self.cache_directory).with_context(|| {
+            format!(
+                "Could not create input directory {}",
+                self.cache_directory.display()
+            )
+        })?;
+        fs::write(&path, &input_text)
+            .with_context(|| format!("Could not write input file {}", path.display()))?;
+
+        Ok(ChallengeInput::new(&input_text))
+    }
+}
+
+fn day_input_path(directory: &Path, day: u8) -> PathBuf {
+    directory.join(format!("day_{:02}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::rc::Rc;
+    use std::thread;
+
+    use spectral::prelude::*;
+
+    use super::*;
+
+    struct RecordingFetcher {
+        fetcher: DirectoryInputFetcher,
+        fetched_days: Rc<RefCell<Vec<u8>>>,
+    }
+
+    impl InputFetcher for RecordingFetcher {
+        fn fetch(&self, day: u8) -> anyhow::Result<String> {
+            self.fetched_days.borrow_mut().push(day);
+            self.fetcher.fetch(day)
+        }
+    }
+
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir()
+            .join(format!("advent-of-code-2020-{}", std::process::id()))
+            .join(name);
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn serve_single_response(
+        status_line: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _address) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            write!(
+                stream,
+                "{}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status_line,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn reads_cached_input_without_fetching() {
+        let cache_directory = test_directory("cache_hit");
+        fs::write(cache_directory.join("day_01.txt"), "1721\r\n979\r\n").unwrap();
+        let fetched_days = Rc::new(RefCell::new(vec![]));
+        let fetcher = RecordingFetcher {
+            fetcher: DirectoryInputFetcher::new(test_directory("cache_hit_source")),
+            fetched_days: fetched_days.clone(),
+        };
+        let provider = InputProvider::new(cache_directory, Some(Box::new(fetcher)));
+
+        assert_that(&provider.input(1).unwrap().raw()).is_equal_to("1721\n979\n");
+        assert_that(&*fetched_days.borrow()).is_empty();
+    }
+
+    #[test]
+    fn fetches_and_caches_input_on_cache_miss() {
+        let cache_directory = test_directory("cache_miss").join("inputs");
+        let source_directory = test_directory("cache_miss_source");
+        fs::write(source_directory.join("day_15.txt"), "0,3,6\n").unwrap();
+        let fetched_days = Rc::new(RefCell::new(vec![]));
+        let fetcher = RecordingFetcher {
+            fetcher: DirectoryInputFetcher::new(source_directory),
+            fetched_days: fetched_days.clone(),
+        };
+        let provider = InputProvider::new(cache_directory.clone(), Some(Box::new(fetcher)));
+
+        assert_that(&provider.input(15).unwrap().raw()).is_equal_to("0,3,6\n");
+        assert_that(&provider.input(15).unwrap().raw()).is_equal_to("0,3,6\n");
+        assert_that(&*fetched_days.borrow()).is_equal_to(vec![15]);
+        assert_that(&fs::read_to_string(cache_directory.join("day_15.txt")).unwrap())
+            .is_equal_to("0,3,6\n".to_string());
+    }
+
+    #[test]
+    fn fails_to_provide_input_when_fetch_fails() {
+        let cache_directory = test_directory("fetch_error");
+        let provider = InputProvider::new(
+            cache_directory.clone(),
+            Some(Box::new(DirectoryInputFetcher::new(test_directory(
+                "fetch_error_source",
+            )))),
+        );
+
+        assert_that(&provider.input(2).is_err()).is_true();
+        assert_that(&cache_directory.join("day_02.txt").exists()).is_false();
+    }
+
+    #[test]
+    fn fails_to_provide_input_without_fetcher_on_cache_miss() {
+        let provider = InputProvider::new(test_directory("no_fetcher"), None);
+
+        assert_that(&provider.input(3).is_err()).is_true();
+    }
+
+    #[test]
+    fn fetches_input_over_http_with_session_token() {
+        let (base_url, server) = serve_single_response("HTTP/1.1 200 OK", "0,3,6\n");
+
+        let input_text = HttpInputFetcher::new(&base_url, "abc123")
+            .fetch(15)
+            .unwrap();
+        let request = server.join().unwrap();
+
+        assert_that(&input_text).is_equal_to("0,3,6\n".to_string());
+        assert_that(&request.starts_with("GET /day/15/input HTTP/1.1")).is_true();
+        assert_that(&request.to_lowercase().contains("cookie: session=abc123")).is_true();
+    }
+
+    #[test]
+    fn fails_to_fetch_input_over_http_on_error_status() {
+        let (base_url, server) = serve_single_response("HTTP/1.1 404 Not Found", "Not found");
+
+        let result = HttpInputFetcher::new(&base_url, "abc123").fetch(26);
+        server.join().unwrap();
+
+        assert_that(&result.is_err()).is_true();
+    }
+}
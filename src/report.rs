@@ -0,0 +1,270 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use advent_of_code_2020::answer::Answer;
+use advent_of_code_2020::challenge::Challenge;
+use advent_of_code_2020::verification::{ExpectedAnswers, Verdict};
+
+#[derive(Copy, Clone)]
+pub struct StageTimings {
+    reading: Duration,
+    parsing: Duration,
+    solving: Duration,
+}
+
+impl StageTimings {
+    pub fn new(reading: Duration, parsing: Duration, solving: Duration) -> Self {
+        StageTimings {
+            reading,
+            parsing,
+            solving,
+        }
+    }
+
+    fn total(&self) -> Duration {
+        self.reading + self.parsing + self.solving
+    }
+}
+
+pub struct ChallengeOutcome {
+    challenge: Challenge,
+    result: anyhow::Result<Answer>,
+    timings: Vec<StageTimings>,
+    verdict: Option<Verdict>,
+}
+
+impl ChallengeOutcome {
+    pub fn new(
+        challenge: Challenge,
+        result: anyhow::Result<Answer>,
+        timings: Vec<StageTimings>,
+    ) -> Self {
+        ChallengeOutcome {
+            challenge,
+            result,
+            timings,
+            verdict: None,
+        }
+    }
+
+    pub fn check(&mut self, answers: &ExpectedAnswers) {
+        if let Ok(answer) = &self.result {
+            self.verdict = Some(answers.verify(self.challenge, answer));
+        }
+    }
+
+    pub fn failed_check(&self) -> bool {
+        matches!(self.verdict, Some(Verdict::Fail { .. }))
+    }
+
+    pub fn into_result(self) -> anyhow::Result<Answer> {
+        self.result
+    }
+
+    fn answer_cell(&self) -> String {
+        match &self.result {
+            Ok(answer) => answer.to_string(),
+            Err(error) => format!("Error: {:#}", error),
+        }
+    }
+
+    fn check_cell(&self) -> String {
+        match &self.verdict {
+            Some(Verdict::Pass) => "pass".to_string(),
+            Some(Verdict::Fail { .. }) => "fail".to_string(),
+            Some(Verdict::Unknown) => "unknown".to_string(),
+            None => "-".to_string(),
+        }
+    }
+
+    fn stage_cell(&self, stage: impl Fn(&StageTimings) -> Duration) -> String {
+        let durations = self.sorted_durations(stage);
+
+        match durations.as_slice() {
+            [] => "-".to_string(),
+            [duration] => format!("{:.3?}", duration),
+            _ => format!(
+                "{:.3?} / {:.3?} / {:.3?}",
+                durations.first().unwrap(),
+                durations[durations.len() / 2],
+                durations.last().unwrap()
+            ),
+        }
+    }
+
+    fn median_seconds(&self, stage: impl Fn(&StageTimings) -> Duration) -> Value {
+        let durations = self.sorted_durations(stage);
+
+        match durations.get(durations.len() / 2) {
+            Some(duration) => json!(duration.as_secs_f64()),
+            None => Value::Null,
+        }
+    }
+
+    fn sorted_durations(&self, stage: impl Fn(&StageTimings) -> Duration) -> Vec<Duration> {
+        let mut durations: Vec<Duration> = self.timings.iter().map(stage).collect();
+        durations.sort();
+        durations
+    }
+
+    fn json(&self) -> Value {
+        let mut object = json!({
+            "day": self.challenge.day(),
+            "part": self.challenge.part().number(),
+            "timing": {
+                "read_seconds": self.median_seconds(|timings| timings.reading),
+                "parse_seconds": self.median_seconds(|timings| timings.parsing),
+                "solve_seconds": self.median_seconds(|timings| timings.solving),
+                "total_seconds": self.median_seconds(StageTimings::total),
+            },
+        });
+
+        match &self.result {
+            Ok(answer) => {
+                object["answer"] = json!(answer);
+                object["answer_type"] = json!(answer.answer_type());
+            }
+            Err(error) => {
+                object["answer"] = Value::Null;
+                object["answer_type"] = Value::Null;
+                object["error"] = json!(format!("{:#}", error));
+            }
+        }
+        if self.verdict.is_some() {
+            object["check"] = json!(self.check_cell());
+        }
+
+        object
+    }
+}
+
+pub fn summary_table(outcomes: &[ChallengeOutcome]) -> String {
+    let rows = outcomes
+        .iter()
+        .map(|outcome| {
+            vec![
+                outcome.challenge.day().to_string(),
+                outcome.challenge.part().to_string(),
+                outcome.answer_cell(),
+                outcome.stage_cell(StageTimings::total),
+            ]
+        })
+        .collect();
+
+    render_checked_table(&["Day", "Part", "Answer", "Time"], rows, outcomes)
+}
+
+pub fn timing_table(outcomes: &[ChallengeOutcome], repeat: usize) -> String {
+    let rows = outcomes
+        .iter()
+        .map(|outcome| {
+            vec![
+                outcome.challenge.day().to_string(),
+                outcome.challenge.part().to_string(),
+                outcome.answer_cell(),
+                outcome.stage_cell(|timings| timings.reading),
+                outcome.stage_cell(|timings| timings.parsing),
+                outcome.stage_cell(|timings| timings.solving),
+            ]
+        })
+        .collect();
+
+    if repeat > 1 {
+        render_checked_table(
+            &[
+                "Day",
+                "Part",
+                "Answer",
+                "Read (min / median / max)",
+                "Parse (min / median / max)",
+                "Solve (min / median / max)",
+            ],
+            rows,
+            outcomes,
+        )
+    } else {
+        render_checked_table(
+            &["Day", "Part", "Answer", "Read", "Parse", "Solve"],
+            rows,
+            outcomes,
+        )
+    }
+}
+
+pub fn json_lines(outcomes: &[ChallengeOutcome]) -> String {
+    outcomes
+        .iter()
+        .map(|outcome| format!("{}\n", outcome.json()))
+        .collect()
+}
+
+pub fn plain_answers(outcomes: &[ChallengeOutcome]) -> String {
+    outcomes
+        .iter()
+        .map(|outcome| match &outcome.result {
+            Ok(answer) => format!("{}\n", answer),
+            Err(_error) => "\n".to_string(),
+        })
+        .collect()
+}
+
+pub fn check_report(outcomes: &[ChallengeOutcome]) -> String {
+    outcomes
+        .iter()
+        .filter_map(|outcome| match &outcome.verdict {
+            Some(Verdict::Fail { expected, actual }) => Some(format!(
+                "Day {} part {} failed check:\n  expected: {}\n  actual:   {}\n",
+                outcome.challenge.day(),
+                outcome.challenge.part(),
+                expected,
+                actual
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn render_checked_table(
+    header: &[&str],
+    mut rows: Vec<Vec<String>>,
+    outcomes: &[ChallengeOutcome],
+) -> String {
+    if outcomes.iter().all(|outcome| outcome.verdict.is_none()) {
+        return render_table(header, rows);
+    }
+
+    let header: Vec<&str> = header.iter().copied().chain(Some("Check")).collect();
+    rows.iter_mut()
+        .zip(outcomes)
+        .for_each(|(row, outcome)| row.push(outcome.check_cell()));
+
+    render_table(&header, rows)
+}
+
+fn render_table(header: &[&str], rows: Vec<Vec<String>>) -> String {
+    let header: Vec<String> = header.iter().map(ToString::to_string).collect();
+    let table: Vec<Vec<String>> = std::iter::once(header).chain(rows).collect();
+
+    let mut widths = vec![0; table[0].len()];
+    table.iter().for_each(|row| {
+        row.iter()
+            .enumerate()
+            .for_each(|(column, cell)| widths[column] = widths[column].max(cell.chars().count()))
+    });
+
+    table
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(column, cell)| match column {
+                    2 => format!("{:<width$}", cell, width = widths[column]),
+                    _ => format!("{:>width$}", cell, width = widths[column]),
+                })
+                .collect();
+            format!("{}\n", cells.join("  ").trim_end())
+        })
+        .collect()
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::challenge::{Challenge, ChallengePart};
+use crate::error::ChallengeError;
+use crate::input::ChallengeInput;
+
+pub trait Solver {
+    type Input;
+    type Answer: Into<Answer>;
+
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> &[ChallengePart] {
+        &[ChallengePart::One, ChallengePart::Two]
+    }
+
+    fn parse_input(&self, input: &ChallengeInput) -> anyhow::Result<Self::Input>;
+
+    fn solve(&self, part: ChallengePart, input: Self::Input) -> anyhow::Result<Self::Answer>;
+
+    fn renders(&self) -> bool {
+        false
+    }
+
+    fn render(&self, _part: ChallengePart, _input: Self::Input) -> anyhow::Result<String> {
+        Err(anyhow::Error::new(ChallengeError::NotImplemented(format!(
+            "Day {} has nothing to render",
+            self.day()
+        ))))
+    }
+}
+
+pub struct SolveTimings {
+    parsing: Duration,
+    solving: Duration,
+}
+
+impl SolveTimings {
+    pub fn parsing(&self) -> Duration {
+        self.parsing
+    }
+
+    pub fn solving(&self) -> Duration {
+        self.solving
+    }
+}
+
+trait ChallengeSolver {
+    fn challenge_parts(&self) -> &[ChallengePart];
+
+    fn solve_challenge(
+        &self,
+        part: ChallengePart,
+        input: &ChallengeInput,
+    ) -> anyhow::Result<(Answer, SolveTimings)>;
+
+    fn renders_challenge(&self) -> bool;
+
+    fn render_challenge(
+        &self,
+        part: ChallengePart,
+        input: &ChallengeInput,
+    ) -> anyhow::Result<String>;
+}
+
+impl<S: Solver> ChallengeSolver for S {
+    fn challenge_parts(&self) -> &[ChallengePart] {
+        self.parts()
+    }
+
+    fn solve_challenge(
+        &self,
+        part: ChallengePart,
+        input: &ChallengeInput,
+    ) -> anyhow::Result<(Answer, SolveTimings)> {
+        let parse_start = Instant::now();
+        let input = self.parse_input(input)?;
+        let parsing = parse_start.elapsed();
+
+        let solve_start = Instant::now();
+        let answer = self.solve(part, input)?;
+        let solving = solve_start.elapsed();
+
+        Ok((answer.into(), SolveTimings { parsing, solving }))
+    }
+
+    fn renders_challenge(&self) -> bool {
+        self.renders()
+    }
+
+    fn render_challenge(
+        &self,
+        part: ChallengePart,
+        input: &ChallengeInput,
+    ) -> anyhow::Result<String> {
+        self.render(part, self.parse_input(input)?)
+    }
+}
+
+pub struct SolverRegistry {
+    solvers: HashMap<u8, Box<dyn ChallengeSolver>>,
+}
+
+impl SolverRegistry {
+    pub fn new() -> Self {
+        SolverRegistry {
+            solvers: HashMap::new(),
+        }
+    }
+
+    pub fn register<S: Solver + 'static>(&mut self, solver: S) {
+        self.solvers.insert(solver.day(), Box::new(solver));
+    }
+
+    pub fn solve(&self, challenge: Challenge, input: &ChallengeInput) -> anyhow::Result<Answer> {
+        let (answer, _timings) = self.solve_with_timings(challenge, input)?;
+
+        Ok(answer)
+    }
+
+    pub fn solve_with_timings(
+        &self,
+        challenge: Challenge,
+        input: &ChallengeInput,
+    ) -> anyhow::Result<(Answer, SolveTimings)> {
+        self.solver(challenge)?
+            .solve_challenge(challenge.part(), input)
+    }
+
+    pub fn ensure_implemented(&self, challenge: Challenge) -> anyhow::Result<()> {
+        self.solver(challenge).map(|_solver| ())
+    }
+
+    pub fn render(&self, challenge: Challenge, input: &ChallengeInput) -> anyhow::Result<String> {
+        self.renderer(challenge)?
+            .render_challenge(challenge.part(), input)
+    }
+
+    pub fn ensure_renderable(&self, challenge: Challenge) -> anyhow::Result<()> {
+        self.renderer(challenge).map(|_solver| ())
+    }
+
+    pub fn challenges_of_day(&self, day: u8) -> Vec<Challenge> {
+        let parts = match self.solvers.get(&day) {
+            Some(solver) => solver.challenge_parts(),
+            None => &[ChallengePart::One, ChallengePart::Two],
+        };
+
+        parts
+            .iter()
+            .map(|part| Challenge::new(day, *part))
+            .collect()
+    }
+
+    fn solver(&self, challenge: Challenge) -> anyhow::Result<&dyn ChallengeSolver> {
+        let solver = self.solvers.get(&challenge.day()).ok_or_else(|| {
+            anyhow::Error::new(ChallengeError::NotImplemented(format!(
+                "Day {} has not been implemented",
+                challenge.day()
+            )))
+        })?;
+
+        if !solver.challenge_parts().contains(&challenge.part()) {
+            return Err(anyhow::Error::new(ChallengeError::NotImplemented(format!(
+                "Day {} has no part {}",
+                challenge.day(),
+                challenge.part()
+            ))));
+        }
+
+        Ok(solver.as_ref())
+    }
+
+    fn renderer(&self, challenge: Challenge) -> anyhow::Result<&dyn ChallengeSolver> {
+        let solver = self.solver(challenge)?;
+
+        if !solver.renders_challenge() {
+            return Err(anyhow::Error::new(ChallengeError::NotImplemented(format!(
+                "Day {} has nothing to render",
+                challenge.day()
+            ))));
+        }
+
+        Ok(solver)
+    }
+}
+
+impl Default for SolverRegistry {
+    fn default() -> Self {
+        let mut registry = SolverRegistry::new();
+
+        registry.register(crate::day_01::Day01Solver);
+        registry.register(crate::day_02::Day02Solver);
+        registry.register(crate::day_03::Day03Solver);
+        registry.register(crate::day_04::Day04Solver);
+        registry.register(crate::day_05::Day05Solver);
+        registry.register(crate::day_06::Day06Solver);
+        registry.register(crate::day_07::Day07Solver);
+        registry.register(crate::day_08::Day08Solver);
+        registry.register(crate::day_09::Day09Solver);
+        registry.register(crate::day_10::Day10Solver);
+        registry.register(crate::day_11::Day11Solver);
+        registry.register(crate::day_12::Day12Solver);
+        registry.register(crate::day_13::Day13Solver);
+        registry.register(crate::day_14::Day14Solver);
+        registry.register(crate::day_15::Day15Solver);
+        registry.register(crate::day_16::Day16Solver);
+        registry.register(crate::day_17::Day17Solver);
+        registry.register(crate::day_18::Day18Solver);
+        registry.register(crate::day_19::Day19Solver);
+        registry.register(crate::day_20::Day20Solver);
+        registry.register(crate::day_21::Day21Solver);
+        registry.register(crate::day_22::Day22Solver);
+        registry.register(crate::day_23::Day23Solver);
+        registry.register(crate::day_24::Day24Solver);
+        registry.register(crate::day_25::Day25Solver);
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn solves_registered_challenge() {
+        let registry = SolverRegistry::default();
+        let challenge: Challenge = "1.1".parse().unwrap();
+        let input = ChallengeInput::new("1721\n979\n366\n299\n675\n1456\n");
+
+        assert_that(&registry.solve(challenge, &input).unwrap())
+            .is_equal_to(Answer::Integer(514579));
+    }
+
+    #[test]
+    fn solves_typed_answer() {
+        let registry = SolverRegistry::default();
+        let input = ChallengeInput::new("mxmx (contains dairy)\n");
+
+        assert_that(
+            &registry
+                .solve("1.1".parse().unwrap(), &ChallengeInput::new("1010\n1010\n"))
+                .unwrap()
+                .answer_type(),
+        )
+        .is_equal_to("integer");
+        assert_that(
+            &registry
+                .solve("21.2".parse().unwrap(), &input)
+                .unwrap()
+                .answer_type(),
+        )
+        .is_equal_to("string");
+    }
+
+    #[test]
+    fn fails_to_solve_unregistered_challenge() {
+        let registry = SolverRegistry::new();
+        let challenge: Challenge = "1.1".parse().unwrap();
+
+        assert_that(&registry.solve(challenge, &ChallengeInput::new("")).is_err()).is_true();
+        assert_that(&registry.ensure_implemented(challenge).is_err()).is_true();
+    }
+
+    #[test]
+    fn fails_to_solve_undeclared_part() {
+        let registry = SolverRegistry::default();
+        let challenge: Challenge = "25.2".parse().unwrap();
+
+        assert_that(&registry.ensure_implemented(challenge).is_err()).is_true();
+        assert_that(&registry.challenges_of_day(25)).is_equal_to(vec!["25.1".parse().unwrap()]);
+    }
+
+    #[test]
+    fn fails_to_render_challenge_without_rendering() {
+        let registry = SolverRegistry::default();
+        let challenge: Challenge = "1.1".parse().unwrap();
+
+        assert_that(&registry.ensure_renderable(challenge).is_err()).is_true();
+        assert_that(
+            &registry
+                .render(challenge, &ChallengeInput::new("1010\n1010\n"))
+                .is_err(),
+        )
+        .is_true();
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use serde_json::Value;
+
+use crate::answer::Answer;
+use crate::challenge::Challenge;
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer, actual: Answer },
+    Unknown,
+}
+
+pub struct ExpectedAnswers {
+    answers: HashMap<Challenge, Answer>,
+}
+
+impl ExpectedAnswers {
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Could not read answers file {}", path.display()))?;
+
+        Self::from_json(&text)
+            .with_context(|| format!("Could not parse answers file {}", path.display()))
+    }
+
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        let days: HashMap<String, HashMap<String, Value>> = serde_json::from_str(text)?;
+
+        let mut answers = HashMap::new();
+        for (day, parts) in days {
+            for (part, value) in parts {
+                let challenge: Challenge =
+                    format!("{}.{}", day, part).parse().with_context(|| {
+                        format!("Invalid challenge day {} part {} in answers", day, part)
+                    })?;
+                let answer = match value {
+                    Value::String(answer) => Answer::String(answer),
+                    Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                        (Some(answer), _) => Answer::Integer(answer),
+                        (None, Some(answer)) => Answer::SignedInteger(answer),
+                        (None, None) => {
+                            return Err(anyhow::Error::msg(format!(
+                                "Answer for day {} part {} must be an integer, not {}",
+                                day, part, number
+                            )))
+                        }
+                    },
+                    _ => {
+                        return Err(anyhow::Error::msg(format!(
+                            "Answer for day {} part {} must be a string or a number",
+                            day, part
+                        )))
+                    }
+                };
+                answers.insert(challenge, answer);
+            }
+        }
+
+        Ok(ExpectedAnswers { answers })
+    }
+
+    pub fn expected(&self, challenge: Challenge) -> Option<&Answer> {
+        self.answers.get(&challenge)
+    }
+
+    pub fn verify(&self, challenge: Challenge, answer: &Answer) -> Verdict {
+        match self.expected(challenge) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+                actual: answer.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn challenge(challenge: &str) -> Challenge {
+        challenge.parse().unwrap()
+    }
+
+    #[test]
+    fn verifies_answers_keyed_by_day_and_part() {
+        let answers = ExpectedAnswers::from_json(
+            r#"{ "1": { "1": 514579, "2": 241861950 }, "8": { "1": 5 }, "23": { "1": "67384529" } }"#,
+        )
+        .unwrap();
+
+        assert_that(&answers.verify(challenge("1.1"), &Answer::from(514579_u64)))
+            .is_equal_to(Verdict::Pass);
+        assert_that(&answers.verify(challenge("8.1"), &Answer::from(5_i64)))
+            .is_equal_to(Verdict::Pass);
+        assert_that(&answers.verify(challenge("23.1"), &Answer::from("67384529".to_string())))
+            .is_equal_to(Verdict::Pass);
+        assert_that(&answers.verify(challenge("1.2"), &Answer::from(1_u64))).is_equal_to(
+            Verdict::Fail {
+                expected: Answer::from(241861950_u64),
+                actual: Answer::from(1_u64),
+            },
+        );
+        assert_that(&answers.verify(challenge("2.1"), &Answer::from(2_usize)))
+            .is_equal_to(Verdict::Unknown);
+    }
+
+    #[test]
+    fn fails_answers_of_a_different_type() {
+        let answers = ExpectedAnswers::from_json(r#"{ "23": { "1": 67384529 } }"#).unwrap();
+
+        assert_that(&answers.verify(challenge("23.1"), &Answer::from("67384529".to_string())))
+            .is_equal_to(Verdict::Fail {
+                expected: Answer::from(67384529_u64),
+                actual: Answer::from("67384529".to_string()),
+            });
+    }
+
+    #[test]
+    fn fails_to_parse_answers_for_invalid_challenge() {
+        assert_that(&ExpectedAnswers::from_json(r#"{ "1": { "3": 1 } }"#).is_err()).is_true();
+    }
+
+    #[test]
+    fn fails_to_parse_answers_that_are_not_strings_or_numbers() {
+        let error = ExpectedAnswers::from_json(r#"{ "1": { "2": [1] } }"#)
+            .err()
+            .unwrap();
+
+        assert_that(&error.to_string())
+            .is_equal_to("Answer for day 1 part 2 must be a string or a number".to_string());
+    }
+
+    #[test]
+    fn fails_to_parse_answers_that_are_not_integers() {
+        let error = ExpectedAnswers::from_json(r#"{ "8": { "1": 1.5 } }"#)
+            .err()
+            .unwrap();
+
+        assert_that(&error.to_string())
+            .is_equal_to("Answer for day 8 part 1 must be an integer, not 1.5".to_string());
+    }
+}
@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::PathBuf;
+
+fn check_output(challenge: &str, answers_file_name: &str, answers: &str) -> std::process::Output {
+    let answers_path: PathBuf = [env!("CARGO_TARGET_TMPDIR"), answers_file_name]
+        .iter()
+        .collect();
+    fs::write(&answers_path, answers).unwrap();
+
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    cmd.args([
+        "-c",
+        challenge,
+        "--input-dir",
+        "sample_data",
+        "--check",
+        "--answers",
+    ])
+    .arg(&answers_path)
+    .output()
+    .unwrap()
+}
+
+#[test]
+fn reports_passing_answers() {
+    let output = check_output(
+        "1-2",
+        "passing_answers.json",
+        r#"{ "1": { "1": 793524, "2": 61515678 }, "2": { "1": 393, "2": 690 } }"#,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.lines().next().unwrap().ends_with("Check"));
+    assert_eq!(stdout.matches("pass").count(), 4);
+}
+
+#[test]
+fn reports_failing_and_unknown_answers() {
+    let output = check_output(
+        "1-2",
+        "failing_answers.json",
+        r#"{ "1": { "1": 793524, "2": 1 }, "2": { "1": 393 } }"#,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(5));
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines[2].starts_with("  1     2  61515678"));
+    assert!(lines[2].ends_with(" fail"));
+    assert!(lines[4].ends_with(" unknown"));
+    assert!(stdout.ends_with("Day 1 part 2 failed check:\n  expected: 1\n  actual:   61515678\n"));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Error: 1 of 4 answers failed the check\n"
+    );
+}
+
+#[test]
+fn checks_numeric_and_string_answers_of_the_same_day() {
+    let output = check_output(
+        "21.*",
+        "day_21_answers.json",
+        r#"{ "21": { "1": 2411, "2": "loju,zohy,gavizi,ryxigo,jobuko,nuvipi,vomyho,hixypasy" } }"#,
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert_eq!(stdout.matches("pass").count(), 2);
+}
+
+#[test]
+fn reports_missing_answers_file() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "1.1", "-i", "sample_data/day_01.txt", "--check"])
+        .args(["--answers", "sample_data/answers.json"])
+        .assert()
+        .code(3);
+}
@@ -4,3 +4,8 @@ use crate::challenges::assert_challenge_result;
 fn part_1() {
     assert_challenge_result(10, 1, "3034")
 }
+
+#[test]
+fn part_2() {
+    assert_challenge_result(10, 2, "259172170858496")
+}
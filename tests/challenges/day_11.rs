@@ -4,3 +4,8 @@ use crate::challenges::assert_challenge_result;
 fn part_1() {
     assert_challenge_result(11, 1, "2386")
 }
+
+#[test]
+fn part_2() {
+    assert_challenge_result(11, 2, "2091")
+}
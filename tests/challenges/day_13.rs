@@ -4,3 +4,8 @@ use crate::challenges::assert_challenge_result;
 fn part_1() {
     assert_challenge_result(13, 1, "2382")
 }
+
+#[test]
+fn part_2() {
+    assert_challenge_result(13, 2, "906332393333683")
+}
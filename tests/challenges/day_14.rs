@@ -0,0 +1,11 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(14, 1, "8766747230740")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(14, 2, "24085433509496")
+}
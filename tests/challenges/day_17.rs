@@ -0,0 +1,11 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(17, 1, "197")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(17, 2, "1768")
+}
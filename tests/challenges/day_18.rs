@@ -0,0 +1,11 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(18, 1, "130111593183")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(18, 2, "3736862834361")
+}
@@ -0,0 +1,25 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(20, 1, "50884971555600")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(20, 2, "2948")
+}
+
+#[test]
+fn renders_assembled_image() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", "20.2", "-i", "sample_data/day_20.txt", "--render"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert_eq!(stdout.lines().count(), 96);
+    assert!(stdout.contains('O'));
+}
@@ -0,0 +1,15 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(21, 1, "2411")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(
+        21,
+        2,
+        "loju,zohy,gavizi,ryxigo,jobuko,nuvipi,vomyho,hixypasy",
+    )
+}
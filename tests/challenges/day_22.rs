@@ -0,0 +1,41 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(22, 1, "31673")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(22, 2, "31046")
+}
+
+#[test]
+fn renders_combat_log() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args([
+            "-c",
+            "22.1",
+            "--input-text",
+            "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10\n",
+            "--render",
+        ])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert_eq!(stdout.lines().next(), Some("=== Game 1 ==="));
+    assert_eq!(
+        stdout.lines().last(),
+        Some("The winner of game 1 is Player 2!")
+    );
+    assert_eq!(
+        stdout
+            .lines()
+            .filter(|line| line.starts_with("-- Round"))
+            .count(),
+        29
+    );
+}
@@ -0,0 +1,11 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(23, 1, "38465927")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(23, 2, "9234582868")
+}
@@ -0,0 +1,11 @@
+use crate::challenges::assert_challenge_result;
+
+#[test]
+fn part_1() {
+    assert_challenge_result(24, 1, "106")
+}
+
+#[test]
+fn part_2() {
+    assert_challenge_result(24, 2, "3086")
+}
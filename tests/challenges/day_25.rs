@@ -0,0 +1,30 @@
+use crate::challenges::{assert_challenge_result, challenge_command};
+
+#[test]
+fn part_1() {
+    assert_challenge_result(25, 1, "1386351")
+}
+
+#[test]
+fn part_2_does_not_exist() {
+    let output = challenge_command(25, 2).output().unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Day 25 has no part 2"));
+}
+
+#[test]
+fn part_2_does_not_exist_without_input() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", "25.2", "--input-dir", "sample_data/missing"])
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Error: Day 25 has no part 2\n"
+    );
+}
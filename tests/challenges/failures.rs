@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::PathBuf;
+
+fn assert_challenge_failure(challenge: &str, input_path: &str, exit_code: i32, message: &str) {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", challenge, "-i", input_path])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(exit_code));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!("Error: {}\n", message)
+    );
+}
+
+fn assert_challenge_text_failure(challenge: &str, input_text: &str, exit_code: i32, message: &str) {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", challenge, "--input-text", input_text])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(exit_code));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!("Error: {}\n", message)
+    );
+}
+
+#[test]
+fn rejects_day_outside_advent() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", "26.1", "-i", "sample_data/day_01.txt"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Day 26 is not between 1 and 25"));
+}
+
+#[test]
+fn reports_unimplemented_part() {
+    assert_challenge_failure("25.2", "sample_data/day_25.txt", 2, "Day 25 has no part 2");
+}
+
+#[test]
+fn reports_unimplemented_part_before_looking_for_input() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", "25.2", "--input-dir", "sample_data/missing"])
+        .env("AOC_SESSION", "abc123")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Error: Day 25 has no part 2\n"
+    );
+}
+
+#[test]
+fn reports_input_error() {
+    assert_challenge_failure(
+        "1.1",
+        "sample_data/day_02.txt",
+        3,
+        "invalid digit found in string",
+    );
+}
+
+#[test]
+fn reports_missing_input_file() {
+    assert_challenge_failure(
+        "1.1",
+        "sample_data/day_00.txt",
+        3,
+        "Could not read input file sample_data/day_00.txt: No such file or directory (os error 2)",
+    );
+}
+
+#[test]
+fn reports_no_solution_found() {
+    let input_path: PathBuf = [env!("CARGO_TARGET_TMPDIR"), "no_2020_sum_pair.txt"]
+        .iter()
+        .collect();
+    fs::write(&input_path, "1\n2\n3\n").unwrap();
+
+    assert_challenge_failure(
+        "1.1",
+        input_path.to_str().unwrap(),
+        4,
+        "No pair sums to 2020",
+    );
+}
+
+#[test]
+fn reports_password_line_without_password() {
+    assert_challenge_text_failure(
+        "2.1",
+        "1-3 a",
+        3,
+        "Could not find password after policy in '1-3 a'",
+    );
+}
+
+#[test]
+fn reports_password_policy_position_0() {
+    assert_challenge_text_failure(
+        "2.2",
+        "0-3 a: abc",
+        3,
+        "Password policy positions must start at 1",
+    );
+}
+
+#[test]
+fn reports_seating_rows_of_different_lengths() {
+    assert_challenge_text_failure(
+        "11.1",
+        "L.#\nL",
+        3,
+        "Seating row 2 has 1 elements but row 1 has 3",
+    );
+}
+
+#[test]
+fn reports_missing_encryption_weakness_as_no_solution_found() {
+    assert_challenge_text_failure("9.1", "1\n2\n3", 4, "Did not find weakness");
+}
+
+#[test]
+fn reports_day_without_rendering_before_looking_for_input() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args([
+            "-c",
+            "1.1",
+            "--render",
+            "--input-dir",
+            "sample_data/missing",
+        ])
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Error: Day 1 has nothing to render\n"
+    );
+}
@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+fn format_output(args: &[&str]) -> std::process::Output {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(args).output().unwrap()
+}
+
+fn json_objects(output: &std::process::Output) -> Vec<Value> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn prints_json_object_per_challenge() {
+    let output = format_output(&[
+        "-c",
+        "21.*",
+        "--input-dir",
+        "sample_data",
+        "--format",
+        "json",
+    ]);
+    let objects = json_objects(&output);
+
+    assert!(output.status.success());
+    assert_eq!(objects.len(), 2);
+    assert_eq!(objects[0]["day"], 21);
+    assert_eq!(objects[0]["part"], 1);
+    assert_eq!(objects[0]["answer"], 2411);
+    assert_eq!(objects[0]["answer_type"], "integer");
+    assert_eq!(objects[1]["part"], 2);
+    assert_eq!(
+        objects[1]["answer"],
+        "loju,zohy,gavizi,ryxigo,jobuko,nuvipi,vomyho,hixypasy"
+    );
+    assert_eq!(objects[1]["answer_type"], "string");
+    assert!(objects[1]["timing"]["total_seconds"].is_f64());
+    assert!(objects[1].get("error").is_none());
+}
+
+#[test]
+fn prints_json_error_of_failed_challenge() {
+    let output = format_output(&[
+        "-c",
+        "1.1",
+        "-i",
+        "sample_data/day_02.txt",
+        "--format",
+        "json",
+    ]);
+    let objects = json_objects(&output);
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0]["error"], "invalid digit found in string");
+    assert!(objects[0]["answer"].is_null());
+}
+
+#[test]
+fn prints_only_answers_in_plain_format() {
+    let output = format_output(&[
+        "-c",
+        "4.*",
+        "--input-dir",
+        "sample_data",
+        "--format",
+        "plain",
+    ]);
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "208\n167\n");
+}
+
+#[test]
+fn prints_empty_line_for_failed_challenge_in_plain_format() {
+    let input_dir: PathBuf = [env!("CARGO_TARGET_TMPDIR"), "plain_format_inputs"]
+        .iter()
+        .collect();
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::write(
+        input_dir.join("day_01.txt"),
+        "1721\n979\n366\n299\n675\n1456\n",
+    )
+    .unwrap();
+    fs::write(input_dir.join("day_02.txt"), "1-3 a\n").unwrap();
+
+    let output = format_output(&[
+        "-c",
+        "1-2",
+        "--input-dir",
+        input_dir.to_str().unwrap(),
+        "--format",
+        "plain",
+    ]);
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "514579\n241861950\n\n\n"
+    );
+}
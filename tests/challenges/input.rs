@@ -0,0 +1,89 @@
+#[test]
+fn reads_input_from_stdin() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "15.1", "-i", "-"])
+        .write_stdin("0,3,6\n")
+        .assert()
+        .success()
+        .stdout("Answer: 436\n");
+}
+
+#[test]
+fn reads_input_from_text() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "15.1", "--input-text", "0,3,6"])
+        .assert()
+        .success()
+        .stdout("Answer: 436\n");
+}
+
+#[test]
+fn reads_multiline_input_from_text() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args([
+        "-c",
+        "1.1",
+        "--input-text",
+        "1721\n979\n366\n299\n675\n1456",
+    ])
+    .assert()
+    .success()
+    .stdout("Answer: 514579\n");
+}
+
+#[test]
+fn rejects_input_text_for_multiple_challenges() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "15.*", "--input-text", "0,3,6"])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn reads_records_from_crlf_input_with_padded_blank_lines() {
+    let input_text =
+        "abc\r\n \r\na\r\nb\r\nc\r\n\t\r\nab\r\nac\r\n\r\na\r\na\r\na\r\na\r\n\r\nb\r\n";
+
+    for (challenge, answer) in [("6.1", "11"), ("6.2", "6")] {
+        let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+        cmd.args(["-c", challenge, "--input-text", input_text])
+            .assert()
+            .success()
+            .stdout(format!("Answer: {}\n", answer));
+    }
+}
+
+#[test]
+fn reads_sections_separated_by_padded_blank_lines() {
+    let inputs = [
+        (
+            "16.1",
+            "class: 1-3 or 5-7\nrow: 6-11 or 33-44\nseat: 13-40 or 45-50\n  \nyour ticket:\n7,1,14\n\t\nnearby tickets:\n7,3,47\n40,4,50\n55,2,20\n38,6,12\n",
+            "71",
+        ),
+        (
+            "19.1",
+            "0: 4 1 5\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n \nababbb\nbababa\nabbbab\naaabbb\naaaabbb\n",
+            "2",
+        ),
+        (
+            "22.1",
+            "Player 1:\n9\n2\n6\n3\n1\n   \nPlayer 2:\n5\n8\n4\n7\n10\n",
+            "306",
+        ),
+    ];
+
+    for (challenge, input_text, answer) in inputs {
+        let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+        cmd.args(["-c", challenge, "--input-text", input_text])
+            .assert()
+            .success()
+            .stdout(format!("Answer: {}\n", answer));
+    }
+}
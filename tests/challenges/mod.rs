@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+mod check;
 mod day_01;
 mod day_02;
 mod day_03;
@@ -13,18 +14,33 @@ mod day_10;
 mod day_11;
 mod day_12;
 mod day_13;
+mod day_14;
 mod day_15;
 mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+mod failures;
+mod format;
+mod input;
+mod selection;
+mod timing;
 
 fn challenge_command(day: u8, part: u8) -> assert_cmd::Command {
     let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
 
-    cmd.args(&["-c", format!("{}.{}", day, part).as_str()]);
+    cmd.args(["-c", format!("{}.{}", day, part).as_str()]);
 
     let sample_data_path: PathBuf = ["sample_data", sample_data_file_name(day).as_str()]
         .iter()
         .collect();
-    cmd.args(&["-i", sample_data_path.as_os_str().to_str().unwrap()]);
+    cmd.args(["-i", sample_data_path.as_os_str().to_str().unwrap()]);
 
     cmd
 }
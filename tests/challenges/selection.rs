@@ -0,0 +1,65 @@
+fn summary_rows(challenge_selection: &str) -> Vec<Vec<String>> {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", challenge_selection, "--input-dir", "sample_data"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .take(3)
+                .map(ToString::to_string)
+                .collect()
+        })
+        .collect()
+}
+
+fn expected_rows(rows: &[[&str; 3]]) -> Vec<Vec<String>> {
+    std::iter::once(&["Day", "Part", "Answer"])
+        .chain(rows.iter())
+        .map(|row| row.iter().map(ToString::to_string).collect())
+        .collect()
+}
+
+#[test]
+fn runs_range_of_days() {
+    assert_eq!(
+        summary_rows("1-2"),
+        expected_rows(&[
+            ["1", "1", "793524"],
+            ["1", "2", "61515678"],
+            ["2", "1", "393"],
+            ["2", "2", "690"],
+        ])
+    );
+}
+
+#[test]
+fn runs_every_part_of_day() {
+    assert_eq!(
+        summary_rows("4.*"),
+        expected_rows(&[["4", "1", "208"], ["4", "2", "167"]])
+    );
+}
+
+#[test]
+fn finds_single_challenge_input_in_input_directory() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "25.1", "--input-dir", "sample_data"])
+        .assert()
+        .success()
+        .stdout("Answer: 1386351\n");
+}
+
+#[test]
+fn rejects_input_file_for_multiple_challenges() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "1-2", "-i", "sample_data/day_01.txt"])
+        .assert()
+        .code(3);
+}
@@ -0,0 +1,45 @@
+fn timing_output(args: &[&str]) -> Vec<String> {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+    let output = cmd
+        .args(["-c", "1.1", "-i", "sample_data/day_01.txt", "--time"])
+        .args(args)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn reports_time_of_each_stage() {
+    let lines = timing_output(&[]);
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0].split_whitespace().collect::<Vec<&str>>(),
+        ["Day", "Part", "Answer", "Read", "Parse", "Solve"]
+    );
+    assert_eq!(lines[1].split_whitespace().count(), 6);
+    assert!(lines[1].trim_start().starts_with("1     1  793524"));
+}
+
+#[test]
+fn reports_min_median_and_max_time_of_repeated_runs() {
+    let lines = timing_output(&["--repeat", "3"]);
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].matches("(min / median / max)").count(), 3);
+    assert_eq!(lines[1].matches(" / ").count(), 6);
+}
+
+#[test]
+fn requires_time_mode_to_repeat() {
+    let mut cmd = assert_cmd::Command::cargo_bin("advent-of-code-2020").unwrap();
+
+    cmd.args(["-c", "1.1", "-i", "sample_data/day_01.txt", "--repeat", "3"])
+        .assert()
+        .failure();
+}